@@ -0,0 +1,79 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+/// An answer to a single part of a puzzle.
+///
+/// It's serialized as a plain JSON number or string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Str(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{}", n),
+            Self::UInt(n) => write!(f, "{}", n),
+            Self::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident($inner:ty): $($ty:ty),+) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value as $inner)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Int(i64): i8, i16, i32, i64, isize);
+impl_from_int!(UInt(u64): u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(-3), Answer::Int(-3));
+        assert_eq!(Answer::from(3usize), Answer::UInt(3));
+        assert_eq!(Answer::from("abc"), Answer::Str("abc".to_owned()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Int(-3).to_string(), "-3");
+        assert_eq!(Answer::UInt(1783).to_string(), "1783");
+        assert_eq!(Answer::Str("vzbxxyzz".to_owned()).to_string(), "vzbxxyzz");
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(serde_json::to_string(&Answer::Int(-3)).unwrap(), "-3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("abc")).unwrap(),
+            r#""abc""#
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, path::Path, str::FromStr};
+
+use anyhow::{Context, Error, Result, anyhow};
+use toml::{Table, Value};
+
+use crate::solution::Part;
+
+/// Known correct answers, keyed by year, day and part.
+///
+/// They are stored in a TOML file with a table per day, e.g.:
+///
+/// ```toml
+/// [2015.7]
+/// p1 = 3176
+/// p2 = 14710
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u16, u8, Part), String>);
+
+impl Answers {
+    /// Reads the answers from the file at `path`.
+    pub async fn read(path: &Path) -> Result<Self> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read answers file {}", path.display()))?;
+        content
+            .parse()
+            .with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    /// Returns the expected answer in its textual form.
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+        for (year, days) in s.parse::<Table>()? {
+            let days = days.as_table().context("Year must be a table of days")?;
+            let year: u16 = year
+                .parse()
+                .with_context(|| format!("Invalid year {year:?}"))?;
+            for (day, parts) in days {
+                let parts = parts.as_table().context("Day must be a table of parts")?;
+                let day: u8 = day
+                    .parse()
+                    .with_context(|| format!("Invalid day {day:?}"))?;
+                for (part, answer) in parse_parts(parts)? {
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+        Ok(Self(answers))
+    }
+}
+
+/// Parses a table of answers keyed by `p1` and `p2` into their textual
+/// forms.
+pub fn parse_parts(parts: &Table) -> Result<BTreeMap<Part, String>> {
+    let mut answers = BTreeMap::new();
+    for (part, answer) in parts {
+        let part = match part.as_str() {
+            "p1" => Part::One,
+            "p2" => Part::Two,
+            _ => return Err(anyhow!("Invalid part {part:?}, expected p1 or p2")),
+        };
+        let answer = match answer {
+            Value::Integer(n) => n.to_string(),
+            Value::String(s) => s.clone(),
+            _ => return Err(anyhow!("Answer must be an integer or a string")),
+        };
+        answers.insert(part, answer);
+    }
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::solution::Part;
+
+    #[test]
+    fn test_from_str() {
+        let answers: Answers = "[2015.1]\np1 = 232\n\n[2015.11]\np2 = \"vzcaabcc\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(2015, 1, Part::One), Some("232"));
+        assert_eq!(answers.get(2015, 1, Part::Two), None);
+        assert_eq!(answers.get(2015, 11, Part::Two), Some("vzcaabcc"));
+    }
+
+    #[test]
+    fn test_from_str_invalid_part() {
+        assert!("[2015.1]\np3 = 1\n".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,412 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result, bail, ensure};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::InputArgs,
+    output::{Format, print_json},
+    registry::Registry,
+    runner::PuzzleFilter,
+    solution::{Part, Puzzle},
+    table::Table,
+};
+
+/// Relative slowdown of the median solve time, above which a part is
+/// reported as regressed.
+const REGRESSION_THRESHOLD: f64 = 0.1;
+
+/// Solves every part many times and reports how long parsing and solving took.
+#[derive(clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    filter: PuzzleFilter,
+
+    /// Number of times each part is solved.
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Saves the results as a JSON baseline to the given file.
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Compares the results with a baseline saved earlier with `--save`.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        input.ensure_input_dir("benchmarking multiple puzzles")?;
+
+        let baseline = match &self.baseline {
+            Some(path) => Some(Baseline::read(path).await?),
+            None => None,
+        };
+
+        let registry = Registry::new();
+        let mut benches = vec![];
+        let mut failures = vec![];
+        for puzzle in self.filter.apply(&registry) {
+            let input = match input.read(puzzle).await {
+                Ok(input) => input,
+                Err(err) => {
+                    failures.push(Failure::new(puzzle, None, &err));
+                    continue;
+                }
+            };
+            for part in [Part::One, Part::Two] {
+                match Bench::measure(puzzle, part, &input, self.iterations) {
+                    Ok(bench) => benches.push(bench),
+                    Err(err) => failures.push(Failure::new(puzzle, Some(part), &err)),
+                }
+            }
+        }
+        ensure!(
+            !benches.is_empty() || !failures.is_empty(),
+            "No puzzles match the given filters"
+        );
+
+        match format {
+            Format::Text => print!("{}", summary(&benches, baseline.as_ref())),
+            Format::Json => {
+                print_json(benches.iter().map(|bench| BenchRecord {
+                    bench,
+                    comparison: baseline.as_ref().map(|b| bench.compare(b)),
+                }))?;
+                print_json(&failures)?;
+            }
+        }
+
+        if let Some(path) = &self.save {
+            Baseline { benches }.write(path).await?;
+        }
+
+        if !failures.is_empty() {
+            if format == Format::Text {
+                for failure in &failures {
+                    eprintln!("FAILED {failure}");
+                }
+            }
+            bail!("{} parts failed", failures.len());
+        }
+        Ok(())
+    }
+}
+
+/// Minimum, median and maximum of the measured durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "crate::nanos")]
+    pub min: Duration,
+    #[serde(with = "crate::nanos")]
+    pub median: Duration,
+    #[serde(with = "crate::nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Calculates the statistics of the `samples`, or returns `None` if there
+    /// are none.
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (*samples.get(mid.checked_sub(1)?)? + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Some(Self {
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Results of benchmarking a single part of a puzzle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bench {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    /// Parses the `input` and solves the `part` of the `puzzle` the given
+    /// number of times.
+    pub fn measure(puzzle: &dyn Puzzle, part: Part, input: &str, iterations: u32) -> Result<Self> {
+        let mut parse = vec![];
+        let mut solve = vec![];
+        for _ in 0..iterations {
+            let timed = puzzle.solve_timed(part, input)?;
+            parse.push(timed.parse);
+            solve.push(timed.solve);
+        }
+        Ok(Self {
+            year: puzzle.year(),
+            day: puzzle.day(),
+            part,
+            iterations,
+            parse: Stats::new(parse).context("No iterations were run")?,
+            solve: Stats::new(solve).context("No iterations were run")?,
+        })
+    }
+
+    /// Compares the median solve time with the same part in the `baseline`.
+    fn compare(&self, baseline: &Baseline) -> Comparison {
+        let Some(before) = baseline.get(self.year, self.day, self.part) else {
+            return Comparison::New;
+        };
+        let before = before.solve.median.as_secs_f64();
+        if before == 0.0 {
+            return Comparison::ZeroBaseline;
+        }
+        let after = self.solve.median.as_secs_f64();
+        Comparison::Changed {
+            change: (after - before) / before,
+        }
+    }
+}
+
+/// How the median solve time of a part compares with the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "comparison", rename_all = "snake_case")]
+enum Comparison {
+    /// The part isn't in the baseline.
+    New,
+    /// The part took no time in the baseline, so there's no relative change.
+    ZeroBaseline,
+    Changed {
+        /// Relative change since the baseline, e.g. `0.5` when it's 50%
+        /// slower.
+        change: f64,
+    },
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::New => write!(f, "new"),
+            Self::ZeroBaseline => write!(f, "n/a"),
+            Self::Changed { change } if change > REGRESSION_THRESHOLD => {
+                write!(f, "{:+.1}% REGRESSED", change * 100.0)
+            }
+            Self::Changed { change } => write!(f, "{:+.1}%", change * 100.0),
+        }
+    }
+}
+
+/// Machine-readable results of benchmarking a single part.
+#[derive(Serialize)]
+struct BenchRecord<'a> {
+    #[serde(flatten)]
+    bench: &'a Bench,
+    /// Comparison with the baseline, if one was given.
+    #[serde(flatten)]
+    comparison: Option<Comparison>,
+}
+
+/// A part, or both parts when the input couldn't be read, that couldn't be
+/// benchmarked.
+#[derive(Debug, Serialize)]
+struct Failure {
+    year: u16,
+    day: u8,
+    part: Option<Part>,
+    error: String,
+}
+
+impl Failure {
+    fn new(puzzle: &dyn Puzzle, part: Option<Part>, err: &anyhow::Error) -> Self {
+        Self {
+            year: puzzle.year(),
+            day: puzzle.day(),
+            part,
+            error: format!("{err:#}"),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)?;
+        if let Some(part) = self.part {
+            write!(f, "/{}", part.number())?;
+        }
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// Results of an earlier benchmark, used to spot regressions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub benches: Vec<Bench>,
+}
+
+impl Baseline {
+    pub async fn read(path: &Path) -> Result<Self> {
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline {}", path.display()))
+    }
+
+    pub async fn write(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, content)
+            .await
+            .with_context(|| format!("Failed to write baseline {}", path.display()))
+    }
+
+    fn get(&self, year: u16, day: u8, part: Part) -> Option<&Bench> {
+        self.benches
+            .iter()
+            .find(|b| b.year == year && b.day == day && b.part == part)
+    }
+}
+
+/// Creates a table with a row per benchmarked part.
+fn summary(benches: &[Bench], baseline: Option<&Baseline>) -> Table<8> {
+    let mut table = Table::new([
+        "Year",
+        "Day",
+        "Part",
+        "Parse (median)",
+        "Solve (min)",
+        "Solve (median)",
+        "Solve (max)",
+        "Change",
+    ]);
+    for bench in benches {
+        let change = baseline
+            .map(|b| bench.compare(b).to_string())
+            .unwrap_or_default();
+        table.push([
+            bench.year.to_string(),
+            bench.day.to_string(),
+            bench.part.number().to_string(),
+            format!("{:.2?}", bench.parse.median),
+            format!("{:.2?}", bench.solve.min),
+            format!("{:.2?}", bench.solve.median),
+            format!("{:.2?}", bench.solve.max),
+            change,
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, Bench, BenchRecord, Stats, summary};
+    use crate::solution::Part;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn bench(day: u8, solve: Duration) -> Bench {
+        let stats = Stats {
+            min: solve,
+            median: solve,
+            max: solve,
+        };
+        Bench {
+            year: 2015,
+            day,
+            part: Part::One,
+            iterations: 1,
+            parse: stats,
+            solve: stats,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(Stats::new(vec![]), None);
+        assert_eq!(
+            Stats::new(vec![ms(3), ms(1), ms(2)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            })
+        );
+        assert_eq!(
+            Stats::new(vec![ms(4), ms(1), ms(2), ms(8)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(8)
+            })
+        );
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let baseline = Baseline {
+            benches: vec![bench(7, ms(2))],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert!(json.contains(r#""part":1"#));
+        assert!(json.contains(r#""median":2000000"#));
+        let parsed: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.benches[0].solve, baseline.benches[0].solve);
+    }
+
+    #[test]
+    fn test_summary_change() {
+        let baseline = Baseline {
+            benches: vec![bench(1, ms(10)), bench(2, ms(10)), bench(4, ms(0))],
+        };
+        let benches = [
+            bench(1, ms(9)),
+            bench(2, ms(12)),
+            bench(3, ms(1)),
+            bench(4, ms(1)),
+        ];
+        let summary = summary(&benches, Some(&baseline)).to_string();
+        let changes: Vec<_> = summary
+            .lines()
+            .skip(1)
+            .map(|line| line.rsplit("  ").next().unwrap())
+            .collect();
+        assert_eq!(changes, ["-10.0%", "+20.0% REGRESSED", "new", "n/a"]);
+    }
+
+    #[test]
+    fn test_record_comparison() {
+        let baseline = Baseline {
+            benches: vec![bench(1, ms(0))],
+        };
+        let json = |bench: &Bench, baseline: Option<&Baseline>| {
+            let record = BenchRecord {
+                bench,
+                comparison: baseline.map(|b| bench.compare(b)),
+            };
+            serde_json::to_string(&record).unwrap()
+        };
+        let zero = json(&bench(1, ms(1)), Some(&baseline));
+        assert!(zero.contains(r#""comparison":"zero_baseline""#));
+        assert!(!zero.contains("change"));
+        let new = json(&bench(2, ms(1)), Some(&baseline));
+        assert!(new.contains(r#""comparison":"new""#));
+        assert!(!json(&bench(1, ms(1)), None).contains("comparison"));
+
+        let baseline = Baseline {
+            benches: vec![bench(1, ms(10))],
+        };
+        let changed = json(&bench(1, ms(20)), Some(&baseline));
+        assert!(changed.contains(r#""comparison":"changed","change":1.0"#));
+    }
+}
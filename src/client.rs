@@ -0,0 +1,400 @@
+use std::{fmt::Display, path::PathBuf, time::Duration};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Part;
+
+/// Default address of the Advent of Code website.
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// User agent sent with every request, as asked for by the website authors.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (automated input downloader)"
+);
+
+/// Transport used by the [`Client`] to talk to the website, replaceable with
+/// a mock in tests.
+pub trait Backend: Send + Sync {
+    /// Sends a GET request authenticated with the `session` token and returns
+    /// the body of a successful response.
+    fn get(&self, url: &str, session: &str) -> impl Future<Output = Result<String>> + Send;
+
+    /// Sends a POST request with the `form` data authenticated with the
+    /// `session` token and returns the body of a successful response.
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> impl Future<Output = Result<String>> + Send;
+}
+
+/// [`Backend`] sending real HTTP requests.
+#[derive(Debug, Clone)]
+pub struct HttpBackend(reqwest::Client);
+
+impl HttpBackend {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self(client))
+    }
+}
+
+impl Backend for HttpBackend {
+    async fn get(&self, url: &str, session: &str) -> Result<String> {
+        let response = self
+            .0
+            .get(url)
+            .header(reqwest::header::COOKIE, format!("session={session}"))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Request to {url} failed"))?;
+        response
+            .text()
+            .await
+            .with_context(|| format!("Failed to read response from {url}"))
+    }
+
+    async fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String> {
+        let response = self
+            .0
+            .post(url)
+            .header(reqwest::header::COOKIE, format!("session={session}"))
+            .form(form)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Request to {url} failed"))?;
+        response
+            .text()
+            .await
+            .with_context(|| format!("Failed to read response from {url}"))
+    }
+}
+
+/// Client of the Advent of Code website.
+#[derive(Debug, Clone)]
+pub struct Client<B = HttpBackend> {
+    base_url: String,
+    session: String,
+    backend: B,
+}
+
+impl<B: Backend> Client<B> {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>, backend: B) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+            backend,
+        }
+    }
+
+    /// Downloads the input of the puzzle from the given `year` and `day`.
+    pub async fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.backend.get(&url, &self.session).await
+    }
+
+    /// Submits the `answer` to the `part` of the puzzle from the given `year`
+    /// and `day` and returns what the website thinks about it.
+    pub async fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: impl Display,
+    ) -> Result<Outcome> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let answer = answer.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+        let response = self.backend.post_form(&url, &self.session, &form).await?;
+        Ok(Outcome::from_response(&response))
+    }
+}
+
+/// Feedback on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect {
+        /// Whether the answer was too high or too low, if the website said.
+        hint: Option<Hint>,
+        /// Time to wait before submitting another answer.
+        #[serde(with = "crate::nanos")]
+        wait: Duration,
+    },
+    /// The previous answer was submitted too recently for this one to be
+    /// checked.
+    TooRecent {
+        #[serde(with = "crate::nanos")]
+        wait: Duration,
+    },
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+    /// The response couldn't be understood.
+    Unknown,
+}
+
+/// Direction in which an incorrect answer missed the correct one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    /// Recognizes the outcome from the HTML page returned after submitting
+    /// an answer.
+    fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Self::Correct
+        } else if response.contains("That's not the right answer") {
+            let hint = if response.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Self::Incorrect {
+                hint,
+                wait: parse_retry_wait(response).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if response.contains("You gave an answer too recently") {
+            Self::TooRecent {
+                wait: parse_left_to_wait(response).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if response.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Time to wait before another answer can be submitted.
+    pub fn wait(&self) -> Duration {
+        match self {
+            Self::Incorrect { wait, .. } | Self::TooRecent { wait } => *wait,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Parses the wait time from e.g. "please wait 5 minutes before trying
+/// again".
+fn parse_retry_wait(response: &str) -> Option<Duration> {
+    let (_, rest) = response.split_once("wait ")?;
+    let (count, rest) = rest.split_once(' ')?;
+    if !rest.starts_with("minute") {
+        return None;
+    }
+    let minutes = match count {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// Parses the wait time from e.g. "You have 4m 21s left to wait".
+fn parse_left_to_wait(response: &str) -> Option<Duration> {
+    let (before, _) = response.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("You have ")?;
+    time.split_whitespace()
+        .try_fold(Duration::ZERO, |total, unit| {
+            let secs = match unit.split_at_checked(unit.len().checked_sub(1)?)? {
+                (n, "s") => n.parse::<u64>().ok()?,
+                (n, "m") => n.parse::<u64>().ok()? * 60,
+                (n, "h") => n.parse::<u64>().ok()? * 3600,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(secs))
+        })
+}
+
+/// Options of the connection to the Advent of Code website.
+#[derive(clap::Args, Debug, Clone)]
+pub struct ClientArgs {
+    /// Address of the Advent of Code website.
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Session token of the logged in user, taken from the `session` cookie.
+    /// Overrides the token from the session file.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// File containing the session token.
+    #[arg(long, env = "AOC_SESSION_FILE", default_value = ".session")]
+    session_file: PathBuf,
+}
+
+impl ClientArgs {
+    /// Creates a client sending real HTTP requests.
+    pub async fn client(&self) -> Result<Client> {
+        Ok(Client::new(
+            &self.base_url,
+            self.session().await?,
+            HttpBackend::new()?,
+        ))
+    }
+
+    /// Returns the session token, reading it from the session file if it
+    /// wasn't given explicitly.
+    async fn session(&self) -> Result<String> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => tokio::fs::read_to_string(&self.session_file)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to read session token from {}, set AOC_SESSION or use --session",
+                        self.session_file.display()
+                    )
+                })?,
+        };
+        Ok(session.trim().to_owned())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use std::time::Duration;
+
+    use super::{Client, Hint, HttpBackend, Outcome};
+    use crate::solution::Part;
+
+    /// Starts a local HTTP server answering a single request with the
+    /// `status` and `body`. Returns its address and a handle resolving to
+    /// the received request.
+    pub async fn stub_server(
+        status: u16,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            while has_unread_body(&request) {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = format!(
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (address, handle)
+    }
+
+    /// Checks if the `request` announces a body which wasn't read yet.
+    fn has_unread_body(request: &[u8]) -> bool {
+        let request = String::from_utf8_lossy(request);
+        let Some((head, body)) = request.split_once("\r\n\r\n") else {
+            return true;
+        };
+        let length = head
+            .lines()
+            .find_map(|line| {
+                line.to_lowercase()
+                    .strip_prefix("content-length: ")?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or(0);
+        body.len() < length
+    }
+
+    #[tokio::test]
+    async fn test_input() {
+        let (address, request) = stub_server(200, "1113122113\n").await;
+        let client = Client::new(address + "/", "secret", HttpBackend::new().unwrap());
+        assert_eq!(client.input(2015, 10).await.unwrap(), "1113122113\n");
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with("GET /2015/day/10/input HTTP/1.1\r\n"));
+        assert!(request.contains("cookie: session=secret\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_input_error_status() {
+        let (address, _) = stub_server(404, "Not Found").await;
+        let client = Client::new(address, "secret", HttpBackend::new().unwrap());
+        assert!(client.input(2015, 26).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_submit() {
+        let (address, request) = stub_server(
+            200,
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        )
+        .await;
+        let client = Client::new(address, "secret", HttpBackend::new().unwrap());
+        let outcome = client.submit(2015, 7, Part::Two, 14710).await.unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+
+        let request = request.await.unwrap();
+        assert!(request.starts_with("POST /2015/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=14710"));
+    }
+
+    #[test]
+    fn test_outcome_from_response() {
+        assert_eq!(
+            Outcome::from_response(
+                "That's not the right answer; your answer is too high. \
+                 Please wait one minute before trying again."
+            ),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            ),
+            Outcome::Incorrect {
+                hint: None,
+                wait: Duration::from_secs(300)
+            }
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "You gave an answer too recently; you have to wait after submitting an \
+                 answer before trying again. You have 4m 21s left to wait."
+            ),
+            Outcome::TooRecent {
+                wait: Duration::from_secs(261)
+            }
+        );
+        assert_eq!(
+            Outcome::from_response("You don't seem to be solving the right level."),
+            Outcome::WrongLevel
+        );
+        assert_eq!(Outcome::from_response("<html></html>"), Outcome::Unknown);
+    }
+}
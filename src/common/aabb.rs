@@ -0,0 +1,195 @@
+//! Axis-aligned boxes of points.
+
+use std::iter::Step;
+
+use super::geometry::{PointRangeInclusive, Vec2, VecN};
+
+/// An axis-aligned box of points in an `N`-dimensional grid, with both
+/// corners included. In 2D it is a rectangle.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Aabb<T, const N: usize = 2> {
+    min: VecN<T, N>,
+    max: VecN<T, N>,
+}
+
+impl<T, const N: usize> Aabb<T, N>
+where
+    T: Step + Ord + Copy,
+{
+    /// Creates the box spanned by two opposite corners given in any order.
+    pub fn new(a: VecN<T, N>, b: VecN<T, N>) -> Self {
+        Self {
+            min: a.zip_with(b, Ord::min),
+            max: a.zip_with(b, Ord::max),
+        }
+    }
+
+    /// The corner with the smallest coordinates.
+    pub fn min(&self) -> VecN<T, N> {
+        self.min
+    }
+
+    /// The corner with the largest coordinates.
+    pub fn max(&self) -> VecN<T, N> {
+        self.max
+    }
+
+    pub fn contains(&self, point: VecN<T, N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// Checks if every point of `other` is in this box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Returns the points shared by both boxes, or `None` if they are
+    /// disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.zip_with(other.min, Ord::max);
+        let max = self.max.zip_with(other.max, Ord::min);
+        (0..N)
+            .all(|axis| min[axis] <= max[axis])
+            .then_some(Self { min, max })
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union_bounds(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.zip_with(other.min, Ord::min),
+            max: self.max.zip_with(other.max, Ord::max),
+        }
+    }
+
+    /// Number of points in the box, i.e. its area in 2D, saturating at
+    /// `usize::MAX`.
+    pub fn area(&self) -> usize {
+        (0..N)
+            .map(|axis| {
+                let (steps, _) = T::steps_between(&self.min[axis], &self.max[axis]);
+                steps.saturating_add(1)
+            })
+            .fold(1, usize::saturating_mul)
+    }
+
+    /// Splits the points of this box that are not in `other` into at most
+    /// `2N` disjoint boxes.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(common) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        // Slices off the parts before and after the intersection along every
+        // axis in turn, leaving just the intersection in `rest`.
+        let mut pieces = vec![];
+        let mut rest = *self;
+        for axis in 0..N {
+            if rest.min[axis] < common.min[axis] {
+                let mut piece = rest;
+                piece.max[axis] = T::backward(common.min[axis], 1);
+                pieces.push(piece);
+                rest.min[axis] = common.min[axis];
+            }
+            if rest.max[axis] > common.max[axis] {
+                let mut piece = rest;
+                piece.min[axis] = T::forward(common.max[axis], 1);
+                pieces.push(piece);
+                rest.max[axis] = common.max[axis];
+            }
+        }
+        pieces
+    }
+}
+
+impl<T> Aabb<T>
+where
+    T: Step + Ord + Copy,
+{
+    /// Creates an iterator over the points of the rectangle, row by row.
+    pub fn points(&self) -> PointRangeInclusive<T> {
+        (*self).into()
+    }
+}
+
+/// Takes the whole range, regardless of how far it has been iterated.
+impl<T> From<PointRangeInclusive<T>> for Aabb<T>
+where
+    T: Step + Ord + Copy,
+{
+    fn from(range: PointRangeInclusive<T>) -> Self {
+        let (start, end) = range.corners();
+        Self::new(start, end)
+    }
+}
+
+impl<T> From<Aabb<T>> for PointRangeInclusive<T>
+where
+    T: Step + Ord + Copy,
+{
+    fn from(rect: Aabb<T>) -> Self {
+        Vec2::points_to_inclusive(rect.min, rect.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Aabb;
+    use crate::common::geometry::{PointRangeInclusive, Vec2, Vec3};
+
+    fn rect(a: (i32, i32), b: (i32, i32)) -> Aabb<i32> {
+        Aabb::new(a.into(), b.into())
+    }
+
+    #[test]
+    fn test_new_and_contains() {
+        let r = rect((3, 0), (1, 2));
+        assert_eq!((r.min(), r.max()), (Vec2::new(1, 0), Vec2::new(3, 2)));
+        assert!(r.contains(Vec2::new(1, 2)));
+        assert!(!r.contains(Vec2::new(0, 1)));
+        assert!(r.contains_box(&rect((2, 1), (3, 2))));
+        assert!(!r.contains_box(&rect((2, 1), (4, 2))));
+    }
+
+    #[test]
+    fn test_intersection_and_union() {
+        let (a, b) = (rect((0, 0), (3, 3)), rect((2, 1), (5, 2)));
+        assert_eq!(a.intersection(&b), Some(rect((2, 1), (3, 2))));
+        assert_eq!(a.intersection(&rect((4, 0), (5, 5))), None);
+        assert_eq!(a.union_bounds(&b), rect((0, 0), (5, 3)));
+    }
+
+    #[test]
+    fn test_area() {
+        assert_eq!(rect((0, 0), (0, 0)).area(), 1);
+        assert_eq!(rect((0, 0), (999, 999)).area(), 1_000_000);
+        assert_eq!(Aabb::new(Vec3::new(0, 0, 0), Vec3::new(1, 2, 3)).area(), 24);
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = rect((0, 0), (3, 3));
+        let b = rect((1, 1), (2, 5));
+        let pieces = a.difference(&b);
+        assert_eq!(
+            pieces.iter().map(Aabb::area).sum::<usize>(),
+            a.area() - a.intersection(&b).unwrap().area()
+        );
+        for point in a.points() {
+            let covering = pieces.iter().filter(|piece| piece.contains(point)).count();
+            assert_eq!(covering, usize::from(!b.contains(point)), "{point:?}");
+        }
+
+        assert_eq!(a.difference(&rect((5, 5), (6, 6))), [a]);
+        assert!(a.difference(&rect((-1, -1), (4, 4))).is_empty());
+    }
+
+    #[test]
+    fn test_point_range_conversion() {
+        let range = Vec2::new(2_usize, 1).points_to_inclusive(Vec2::new(0, 0));
+        let rect = Aabb::from(range);
+        assert_eq!(rect.area(), 6);
+        let points: Vec<_> = PointRangeInclusive::from(rect).collect();
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[0], Vec2::new(0, 0));
+    }
+}
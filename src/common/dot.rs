@@ -0,0 +1,121 @@
+//! Writing graphs in the DOT language of Graphviz.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A graph printed in the DOT language by its [`Display`] implementation.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    directed: bool,
+    statements: Vec<String>,
+}
+
+impl Graph {
+    /// Creates a graph with edges going from one node to another.
+    pub fn directed() -> Self {
+        Self {
+            directed: true,
+            statements: vec![],
+        }
+    }
+
+    /// Creates a graph with edges connecting nodes both ways.
+    pub fn undirected() -> Self {
+        Self {
+            directed: false,
+            statements: vec![],
+        }
+    }
+
+    /// Sets the `attributes` of all nodes added after this call.
+    pub fn node_defaults(&mut self, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("node{}", format_attributes(attributes)));
+        self
+    }
+
+    /// Adds a node with the given `id` and `attributes`.
+    pub fn node(&mut self, id: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        self.statements
+            .push(format!("{}{}", quote(id), format_attributes(attributes)));
+        self
+    }
+
+    /// Adds an edge between the nodes `from` and `to`, creating them if they
+    /// weren't added yet.
+    pub fn edge(&mut self, from: &str, to: &str, attributes: &[(&str, &str)]) -> &mut Self {
+        let op = if self.directed { "->" } else { "--" };
+        self.statements.push(format!(
+            "{} {op} {}{}",
+            quote(from),
+            quote(to),
+            format_attributes(attributes)
+        ));
+        self
+    }
+}
+
+impl Display for Graph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = if self.directed { "digraph" } else { "graph" };
+        writeln!(f, "{kind} {{")?;
+        for statement in &self.statements {
+            writeln!(f, "    {statement};")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Quotes the `s` as a DOT string, escaping quotes and backslashes. Line
+/// breaks are kept as `\n` escapes, which Graphviz renders as new lines.
+fn quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+fn format_attributes(attributes: &[(&str, &str)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let attributes: Vec<_> = attributes
+        .iter()
+        .map(|(name, value)| format!("{name}={}", quote(value)))
+        .collect();
+    format!(" [{}]", attributes.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+
+    #[test]
+    fn test_directed() {
+        let mut graph = Graph::directed();
+        graph
+            .node_defaults(&[("shape", "box")])
+            .node("a", &[("label", "a\n\"1\"")])
+            .edge("a", "b", &[]);
+        assert_eq!(
+            graph.to_string(),
+            concat!(
+                "digraph {\n",
+                "    node [shape=\"box\"];\n",
+                "    \"a\" [label=\"a\\n\\\"1\\\"\"];\n",
+                "    \"a\" -> \"b\";\n",
+                "}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_undirected() {
+        let mut graph = Graph::undirected();
+        graph.edge("x", "y", &[("label", "5"), ("color", "red")]);
+        assert_eq!(
+            graph.to_string(),
+            "graph {\n    \"x\" -- \"y\" [label=\"5\", color=\"red\"];\n}\n"
+        );
+    }
+}
@@ -0,0 +1,832 @@
+//! Points, vectors and ranges of points.
+
+use std::{
+    array,
+    cmp::Ordering,
+    error::Error as StdError,
+    iter::{FusedIterator, Product, Step, Sum},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use anyhow::{Context, Error, anyhow};
+
+/// A vector, or a point, in an `N`-dimensional space.
+///
+/// In 2D the `y` axis points down like rows of a grid do, so
+/// [`NORTH`](Vec2::NORTH) is `(0, -1)`. Points are ordered by their last
+/// component first, which is reading order in 2D.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+/// A vector, or a point, in a 2D space.
+pub type Vec2<T> = VecN<T, 2>;
+
+/// A vector, or a point, in a 3D space.
+pub type Vec3<T> = VecN<T, 3>;
+
+impl<T, const N: usize> VecN<T, N> {
+    /// Creates a vector with every component computed by `f` from its index.
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Self {
+        Self(array::from_fn(f))
+    }
+
+    pub fn components(&self) -> &[T; N] {
+        &self.0
+    }
+
+    /// Maps every component of the vector with `f`.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> VecN<U, N> {
+        VecN(self.0.map(f))
+    }
+
+    /// Combines the components of two vectors pairwise with `f`.
+    pub fn zip_with<U, V>(self, rhs: VecN<U, N>, mut f: impl FnMut(T, U) -> V) -> VecN<V, N> {
+        let mut pairs = self.0.into_iter().zip(rhs.0);
+        VecN::from_fn(|_| {
+            let (a, b) = pairs.next().expect("Vectors have the same dimension");
+            f(a, b)
+        })
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Mul<Output = T> + Sum + Copy,
+{
+    pub fn dot(&self, rhs: Self) -> T {
+        self.zip_with(rhs, Mul::mul).0.into_iter().sum()
+    }
+
+    /// Square of the Euclidean length of the vector.
+    pub fn norm_squared(&self) -> T {
+        self.dot(*self)
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Product + Copy,
+{
+    /// Product of all components, i.e. the volume of a box with the sides
+    /// given by the vector.
+    pub fn product(&self) -> T {
+        self.0.into_iter().product()
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Sub<Output = T> + Sum + Ord + Default + Copy,
+{
+    /// Distance to `other` when moving only along the axes.
+    pub fn manhattan(&self, other: Self) -> T {
+        self.abs_diff(other).0.into_iter().sum()
+    }
+
+    /// Distance to `other` when diagonal moves are allowed too.
+    pub fn chebyshev(&self, other: Self) -> T {
+        self.abs_diff(other).0.into_iter().max().unwrap_or_default()
+    }
+
+    /// Sum of absolute values of the components.
+    pub fn manhattan_norm(&self) -> T {
+        self.manhattan(Self::default())
+    }
+
+    /// Largest absolute value of the components.
+    pub fn chebyshev_norm(&self) -> T {
+        self.chebyshev(Self::default())
+    }
+
+    fn abs_diff(&self, other: Self) -> Self {
+        self.zip_with(other, |a, b| if a < b { b - a } else { a - b })
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Step + Copy,
+{
+    /// Creates an iterator over the up to `3^N - 1` points adjacent to this
+    /// one, including the diagonal ones. Points that would overflow `T` are
+    /// skipped.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..3_usize.pow(N as u32)).filter_map(move |mut offsets| {
+            let mut neighbour = self;
+            for component in &mut neighbour.0 {
+                match offsets % 3 {
+                    0 => *component = T::backward_checked(*component, 1)?,
+                    2 => *component = T::forward_checked(*component, 1)?,
+                    _ => {}
+                }
+                offsets /= 3;
+            }
+            (neighbour != self).then_some(neighbour)
+        })
+    }
+
+    /// Creates an iterator over the up to `2N` points adjacent to this one
+    /// along the axes.
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        let steps: [fn(T, usize) -> Option<T>; 2] = [T::backward_checked, T::forward_checked];
+        (0..N).flat_map(move |axis| {
+            steps.into_iter().filter_map(move |step| {
+                let mut neighbour = self;
+                neighbour.0[axis] = step(neighbour.0[axis], 1)?;
+                Some(neighbour)
+            })
+        })
+    }
+}
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<T: Copy> Vec2<T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    pub fn area(&self) -> T {
+        self.x() * self.y()
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    pub fn perimeter(&self) -> T {
+        let half = self.x() + self.y();
+        half + half
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    /// Rotates the vector by 90° clockwise, e.g. from north to east.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y(), self.x())
+    }
+
+    /// Rotates the vector by 90° counterclockwise, e.g. from north to west.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y(), -self.x())
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Step + Copy,
+{
+    /// Creates an iterator over the points of the rectangle with corners at
+    /// this point and `rhs`, row by row. The corners may be given in any
+    /// order.
+    pub fn points_to_inclusive(self, rhs: Vec2<T>) -> PointRangeInclusive<T> {
+        PointRangeInclusive::new(self, rhs, Order::RowMajor)
+    }
+}
+
+macro_rules! impl_directions {
+    ($($t:ty),*) => {$(
+        impl Vec2<$t> {
+            pub const NORTH: Self = Self([0, -1]);
+            pub const SOUTH: Self = Self([0, 1]);
+            pub const EAST: Self = Self([1, 0]);
+            pub const WEST: Self = Self([-1, 0]);
+            pub const NORTH_EAST: Self = Self([1, -1]);
+            pub const NORTH_WEST: Self = Self([-1, -1]);
+            pub const SOUTH_EAST: Self = Self([1, 1]);
+            pub const SOUTH_WEST: Self = Self([-1, 1]);
+
+            /// Unit vectors along the axes, clockwise from north.
+            pub const CARDINALS: [Self; 4] = [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+            /// Unit diagonal vectors, clockwise from north-east.
+            pub const DIAGONALS: [Self; 4] = [
+                Self::NORTH_EAST,
+                Self::SOUTH_EAST,
+                Self::SOUTH_WEST,
+                Self::NORTH_WEST,
+            ];
+        }
+    )*};
+}
+
+impl_directions!(i8, i16, i32, i64, i128, isize);
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Product + Copy,
+{
+    /// Volume of a cuboid with the sides given by the vector.
+    pub fn volume(&self) -> T {
+        self.product()
+    }
+}
+
+impl<T: Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+/// Parses the vector from its components separated either by commas, like
+/// `1,2,3`, or by `x`, like `1x2x3`.
+impl<T, const N: usize> FromStr for VecN<T, N>
+where
+    T: FromStr,
+    T::Err: StdError + Send + Sync + 'static,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let separator = if s.contains(',') { ',' } else { 'x' };
+        let components: Vec<T> = s
+            .split(separator)
+            .map(|component| {
+                let component = component.trim();
+                component
+                    .parse()
+                    .with_context(|| format!("Invalid component {component:?}"))
+            })
+            .try_collect()?;
+        let len = components.len();
+        components
+            .try_into()
+            .map(Self)
+            .map_err(|_| anyhow!("Expected {N} components, got {len}"))
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for VecN<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<T: Ord, const N: usize> PartialOrd for VecN<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<Lhs, Rhs, const N: usize> Add<VecN<Rhs, N>> for VecN<Lhs, N>
+where
+    Lhs: Add<Rhs, Output = Lhs>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: VecN<Rhs, N>) -> Self::Output {
+        self.zip_with(rhs, Add::add)
+    }
+}
+
+impl<Lhs, Rhs, const N: usize> Sub<VecN<Rhs, N>> for VecN<Lhs, N>
+where
+    Lhs: Sub<Rhs, Output = Lhs>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: VecN<Rhs, N>) -> Self::Output {
+        self.zip_with(rhs, Sub::sub)
+    }
+}
+
+impl<Lhs, Rhs, const N: usize> AddAssign<VecN<Rhs, N>> for VecN<Lhs, N>
+where
+    Lhs: AddAssign<Rhs>,
+{
+    fn add_assign(&mut self, rhs: VecN<Rhs, N>) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<Lhs, Rhs, const N: usize> SubAssign<VecN<Rhs, N>> for VecN<Lhs, N>
+where
+    Lhs: SubAssign<Rhs>,
+{
+    fn sub_assign(&mut self, rhs: VecN<Rhs, N>) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+/// Multiplication by a scalar.
+impl<T, const N: usize> Mul<T> for VecN<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>, const N: usize> Neg for VecN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(Neg::neg)
+    }
+}
+
+/// Order in which [`PointRangeInclusive`] yields the points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Row by row, with `x` changing fastest.
+    #[default]
+    RowMajor,
+    /// Column by column, with `y` changing fastest.
+    ColumnMajor,
+}
+
+/// An inclusive range of points in a 2D grid.
+///
+/// The range must have at most `usize::MAX` points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointRangeInclusive<T>
+where
+    T: Step + Copy,
+{
+    min: Vec2<T>,
+    max: Vec2<T>,
+    order: Order,
+    /// Index of the next point yielded from the front.
+    front: usize,
+    /// Index just past the next point yielded from the back.
+    back: usize,
+}
+
+impl<T> PointRangeInclusive<T>
+where
+    T: Step + Copy,
+{
+    /// Creates a range of points of the rectangle with the corners `a` and
+    /// `b`, given in any order, yielding them in the given `order`.
+    pub fn new(a: Vec2<T>, b: Vec2<T>, order: Order) -> Self {
+        let bounds = |a: T, b: T| if a <= b { (a, b) } else { (b, a) };
+        let (min_x, max_x) = bounds(a.x(), b.x());
+        let (min_y, max_y) = bounds(a.y(), b.y());
+        let mut range = Self {
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
+            order,
+            front: 0,
+            back: 0,
+        };
+        let [width, height] = range.size();
+        range.back = width
+            .checked_mul(height)
+            .expect("Range must have at most usize::MAX points");
+        range
+    }
+
+    /// Returns the corners with the smallest and the largest coordinates.
+    pub fn corners(&self) -> (Vec2<T>, Vec2<T>) {
+        (self.min, self.max)
+    }
+
+    /// Checks if the `point` is yet to be yielded from either end.
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        let offset = |axis: usize| {
+            (self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+                .then(|| Self::steps(self.min[axis], point[axis]))
+        };
+        let (Some(x), Some(y)) = (offset(0), offset(1)) else {
+            return false;
+        };
+        let [width, height] = self.size();
+        let index = match self.order {
+            Order::RowMajor => y * width + x,
+            Order::ColumnMajor => x * height + y,
+        };
+        (self.front..self.back).contains(&index)
+    }
+
+    /// Returns the number of points along each axis.
+    fn size(&self) -> [usize; 2] {
+        [0, 1].map(|axis| Self::steps(self.min[axis], self.max[axis]) + 1)
+    }
+
+    /// Returns the point with the given index in the iteration order.
+    fn point(&self, index: usize) -> Vec2<T> {
+        let [width, height] = self.size();
+        let (x, y) = match self.order {
+            Order::RowMajor => (index % width, index / width),
+            Order::ColumnMajor => (index / height, index % height),
+        };
+        Vec2::new(T::forward(self.min.x(), x), T::forward(self.min.y(), y))
+    }
+
+    fn steps(from: T, to: T) -> usize {
+        T::steps_between(&from, &to)
+            .1
+            .expect("Range must have at most usize::MAX points")
+    }
+}
+
+impl<T> Iterator for PointRangeInclusive<T>
+where
+    T: Step + Copy,
+{
+    type Item = Vec2<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n + 1;
+        Some(self.point(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for PointRangeInclusive<T>
+where
+    T: Step + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n + 1;
+        Some(self.point(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for PointRangeInclusive<T> where T: Step + Copy {}
+
+impl<T> FusedIterator for PointRangeInclusive<T> where T: Step + Copy {}
+
+#[cfg(test)]
+mod tests {
+    mod vec_n {
+        use crate::common::geometry::{Vec3, VecN};
+
+        #[test]
+        fn test_arithmetic() {
+            let (a, b) = (VecN([1, 2, 3, 4]), VecN([4, 3, 2, 1]));
+            assert_eq!(a + b, VecN([5; 4]));
+            assert_eq!(a - b, VecN([-3, -1, 1, 3]));
+            assert_eq!(a.dot(b), 20);
+            assert_eq!(a.norm_squared(), 30);
+            assert_eq!(a.product(), 24);
+        }
+
+        #[test]
+        fn test_norms() {
+            let v = VecN([3, -4, 1]);
+            assert_eq!(v.manhattan_norm(), 8);
+            assert_eq!(v.chebyshev_norm(), 4);
+        }
+
+        #[test]
+        fn test_parse() {
+            assert_eq!("2x3x4".parse::<Vec3<u32>>().unwrap(), Vec3::new(2, 3, 4));
+            assert_eq!("1,-2, 3".parse::<Vec3<i32>>().unwrap(), Vec3::new(1, -2, 3));
+            assert_eq!("5,6".parse::<VecN<u8, 2>>().unwrap(), VecN([5, 6]));
+            assert!("2x3".parse::<Vec3<u32>>().is_err());
+            assert!("2x3xa".parse::<Vec3<u32>>().is_err());
+        }
+
+        #[test]
+        fn test_neighbours() {
+            let origin = VecN([0_i32; 3]);
+            assert_eq!(origin.neighbours().count(), 26);
+            assert!(origin.neighbours().all(|n| n.chebyshev_norm() == 1));
+            assert_eq!(VecN([0_i64; 4]).neighbours().count(), 80);
+            assert_eq!(VecN([0_usize; 3]).neighbours().count(), 7);
+
+            let orthogonal: Vec<_> = VecN([1_u8, 0]).orthogonal_neighbours().collect();
+            assert_eq!(orthogonal, [VecN([0, 0]), VecN([2, 0]), VecN([1, 1])]);
+            assert!(
+                origin
+                    .orthogonal_neighbours()
+                    .all(|n| n.manhattan_norm() == 1)
+            );
+        }
+
+        #[test]
+        fn test_ordering() {
+            assert!(VecN([9, 0, 0]) < VecN([0, 0, 1]));
+            assert!(VecN([0, 1, 1]) < VecN([1, 1, 1]));
+        }
+    }
+
+    mod vec2 {
+        use std::collections::BTreeSet;
+
+        use crate::common::geometry::Vec2;
+
+        #[test]
+        fn test_area_and_perimeter() {
+            let v = Vec2::new(2, 3);
+            assert_eq!(v.area(), 6);
+            assert_eq!(v.perimeter(), 10);
+        }
+
+        #[test]
+        fn test_arithmetic() {
+            assert_eq!(Vec2::new(1, 2) + Vec2::new(3, 4), Vec2::new(4, 6));
+            assert_eq!(Vec2::new(1, 2) - Vec2::new(3, 4), Vec2::new(-2, -2));
+            assert_eq!(Vec2::new(1, -2) * 3, Vec2::new(3, -6));
+            assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+
+            let mut v = Vec2::new(1, 2);
+            v += Vec2::new(3, 4);
+            assert_eq!(v, Vec2::new(4, 6));
+            v -= Vec2::new(1, 1);
+            assert_eq!(v, Vec2::new(3, 5));
+        }
+
+        #[test]
+        fn test_distances() {
+            let (a, b) = (Vec2::new(1_u32, 5), Vec2::new(4, 1));
+            assert_eq!(a.manhattan(b), 7);
+            assert_eq!(a.chebyshev(b), 4);
+            assert_eq!(b.manhattan(a), 7);
+        }
+
+        #[test]
+        fn test_directions() {
+            assert_eq!(Vec2::<i32>::NORTH.rotate_right(), Vec2::<i32>::EAST);
+            assert_eq!(Vec2::<i32>::NORTH.rotate_left(), Vec2::<i32>::WEST);
+            let mut dir = Vec2::<i64>::NORTH;
+            for expected in Vec2::<i64>::CARDINALS.into_iter().cycle().skip(1).take(4) {
+                dir = dir.rotate_right();
+                assert_eq!(dir, expected);
+            }
+            assert_eq!(
+                Vec2::<i8>::DIAGONALS[0],
+                Vec2::<i8>::NORTH + Vec2::<i8>::EAST
+            );
+            assert!(
+                Vec2::<i16>::DIAGONALS
+                    .iter()
+                    .all(|d| d.chebyshev_norm() == 1)
+            );
+        }
+
+        #[test]
+        fn test_conversion_and_accessors() {
+            let v = Vec2::from((3, 4));
+            assert_eq!((v.x(), v.y()), (3, 4));
+            assert_eq!((v[0], v[1]), (3, 4));
+        }
+
+        #[test]
+        fn test_ordering() {
+            let points = BTreeSet::from([Vec2::new(2, 0), Vec2::new(0, 1), Vec2::new(1, 0)]);
+            assert_eq!(
+                points.into_iter().collect::<Vec<_>>(),
+                [Vec2::new(1, 0), Vec2::new(2, 0), Vec2::new(0, 1)]
+            );
+        }
+    }
+
+    mod vec3 {
+        use crate::common::geometry::Vec3;
+
+        #[test]
+        fn test_volume() {
+            assert_eq!(Vec3::new(2, 3, 4).volume(), 24);
+        }
+
+        #[test]
+        fn test_arithmetic() {
+            assert_eq!(Vec3::new(1, 2, 3) + Vec3::new(3, 4, 5), Vec3::new(4, 6, 8));
+            assert_eq!(
+                Vec3::new(1, 2, 3) - Vec3::new(3, 4, 5),
+                Vec3::new(-2, -2, -2)
+            );
+            assert_eq!((Vec3::from((1, 2, 3)).x(), Vec3::new(1, 2, 3).z()), (1, 3));
+        }
+    }
+
+    mod point_range_inclusive {
+        use proptest::prelude::*;
+
+        use crate::common::geometry::{Order, PointRangeInclusive, Vec2};
+
+        /// Lists the points of the rectangle with nested loops.
+        fn reference(a: Vec2<i32>, b: Vec2<i32>, order: Order) -> Vec<Vec2<i32>> {
+            let xs = a.x().min(b.x())..=a.x().max(b.x());
+            let ys = a.y().min(b.y())..=a.y().max(b.y());
+            let mut points = vec![];
+            match order {
+                Order::RowMajor => {
+                    for y in ys {
+                        for x in xs.clone() {
+                            points.push(Vec2::new(x, y));
+                        }
+                    }
+                }
+                Order::ColumnMajor => {
+                    for x in xs {
+                        for y in ys.clone() {
+                            points.push(Vec2::new(x, y));
+                        }
+                    }
+                }
+            }
+            points
+        }
+
+        fn point() -> impl Strategy<Value = Vec2<i32>> {
+            (-8..8, -8..8).prop_map(Vec2::from)
+        }
+
+        fn order() -> impl Strategy<Value = Order> {
+            prop_oneof![Just(Order::RowMajor), Just(Order::ColumnMajor)]
+        }
+
+        proptest! {
+            #[test]
+            fn test_matches_reference(a in point(), b in point(), order in order()) {
+                let range = PointRangeInclusive::new(a, b, order);
+                let expected = reference(a, b, order);
+                prop_assert_eq!(range.len(), expected.len());
+                prop_assert_eq!(range.collect::<Vec<_>>(), expected.clone());
+                let reversed: Vec<_> = range.rev().collect();
+                prop_assert!(reversed.into_iter().eq(expected.into_iter().rev()));
+            }
+
+            #[test]
+            fn test_both_ends(
+                a in point(),
+                b in point(),
+                order in order(),
+                from_back in prop::collection::vec(any::<bool>(), 0..300),
+            ) {
+                let mut range = PointRangeInclusive::new(a, b, order);
+                let mut remaining = reference(a, b, order);
+                for from_back in from_back {
+                    let (point, expected) = if from_back {
+                        (range.next_back(), remaining.pop())
+                    } else {
+                        (range.next(), (!remaining.is_empty()).then(|| remaining.remove(0)))
+                    };
+                    prop_assert_eq!(point, expected);
+                    prop_assert_eq!(range.len(), remaining.len());
+                    for p in reference(a, b, order) {
+                        prop_assert_eq!(range.contains(p), remaining.contains(&p));
+                    }
+                }
+            }
+
+            #[test]
+            fn test_nth(a in point(), b in point(), order in order(), n in 0_usize..300) {
+                let mut range = PointRangeInclusive::new(a, b, order);
+                let expected = reference(a, b, order);
+                prop_assert_eq!(range.nth(n), expected.get(n).copied());
+                prop_assert_eq!(range.len(), expected.len().saturating_sub(n + 1));
+            }
+        }
+
+        #[test]
+        fn test_fused() {
+            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(1, 0));
+            assert_eq!(range.by_ref().count(), 2);
+            assert_eq!(range.next(), None);
+            assert_eq!(range.next_back(), None);
+            assert_eq!(range.next(), None);
+        }
+
+        #[test]
+        fn test_contains_outside() {
+            let range = Vec2::new(0_usize, 0).points_to_inclusive(Vec2::new(2, 2));
+            assert!(range.contains(Vec2::new(2, 1)));
+            assert!(!range.contains(Vec2::new(3, 1)));
+        }
+
+        #[test]
+        fn test_reversed_corners() {
+            let range = Vec2::new(2_usize, 1).points_to_inclusive(Vec2::new(0, 0));
+            assert_eq!(range.corners(), (Vec2::new(0, 0), Vec2::new(2, 1)));
+            assert_eq!(range.len(), 6);
+        }
+
+        #[test]
+        fn test_single_point() {
+            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(0, 0));
+            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
+            assert_eq!(range.next(), None);
+        }
+
+        #[test]
+        fn test_horizontal_line_points() {
+            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(3, 0));
+            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(1, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(2, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(3, 0)));
+            assert_eq!(range.next(), None);
+        }
+
+        #[test]
+        fn test_vertical_line_points() {
+            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(0, 3));
+            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(0, 1)));
+            assert_eq!(range.next(), Some(Vec2::new(0, 2)));
+            assert_eq!(range.next(), Some(Vec2::new(0, 3)));
+            assert_eq!(range.next(), None);
+        }
+
+        #[test]
+        fn test_rect_points() {
+            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(1, 1));
+            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(1, 0)));
+            assert_eq!(range.next(), Some(Vec2::new(0, 1)));
+            assert_eq!(range.next(), Some(Vec2::new(1, 1)));
+            assert_eq!(range.next(), None);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Dense two-dimensional grids.
+
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{Context, Result, ensure};
+
+use super::geometry::Vec2;
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    ///
+    /// Either size may be 0, making a grid without any cells.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid `width` cells wide from the `cells` listed row by row.
+    pub fn new(width: usize, cells: Vec<T>) -> Result<Self> {
+        ensure!(width > 0, "Grid must be at least one cell wide");
+        ensure!(
+            cells.len().is_multiple_of(width),
+            "{} cells can't form rows of {width} cells",
+            cells.len()
+        );
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a grid from a character map with a line per row, converting
+    /// every character into a cell with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let width = input
+            .lines()
+            .next()
+            .context("Grid must have a row")?
+            .chars()
+            .count();
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            ensure!(
+                row_width == width,
+                "Row {y} is {row_width} cells wide, expected {width}"
+            );
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).with_context(|| format!("Invalid cell at {x},{y}"))?);
+            }
+        }
+        Self::new(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at the given coordinates, or `None` if they are out
+    /// of the grid.
+    pub fn get(&self, coords: Vec2<usize>) -> Option<&T> {
+        self.index(coords).map(|i| &self.cells[i])
+    }
+
+    /// Returns the mutable cell at the given coordinates, or `None` if they
+    /// are out of the grid.
+    pub fn get_mut(&mut self, coords: Vec2<usize>) -> Option<&mut T> {
+        self.index(coords).map(|i| &mut self.cells[i])
+    }
+
+    /// Creates an iterator over the coordinates and cells of the grid, row
+    /// by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coords(i), cell))
+    }
+
+    /// Creates an iterator over the cells of the grid, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Creates an iterator over coordinates of the up to four cells sharing
+    /// an edge with the given one.
+    pub fn neighbours4(&self, coords: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> + use<T> {
+        let (width, height) = (self.width, self.height);
+        [
+            coords.y().checked_sub(1).map(|y| Vec2::new(coords.x(), y)),
+            coords.x().checked_sub(1).map(|x| Vec2::new(x, coords.y())),
+            (coords.x() + 1 < width).then(|| Vec2::new(coords.x() + 1, coords.y())),
+            (coords.y() + 1 < height).then(|| Vec2::new(coords.x(), coords.y() + 1)),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(move |neighbour| neighbour.x() < width && neighbour.y() < height)
+    }
+
+    /// Creates an iterator over coordinates of the up to eight cells
+    /// surrounding the given one, including the diagonal ones.
+    pub fn neighbours8(&self, coords: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> + use<T> {
+        let (width, height) = (self.width, self.height);
+        let min = Vec2::new(coords.x().saturating_sub(1), coords.y().saturating_sub(1));
+        let max = Vec2::new(coords.x().saturating_add(1), coords.y().saturating_add(1));
+        min.points_to_inclusive(max).filter(move |&neighbour| {
+            neighbour != coords && neighbour.x() < width && neighbour.y() < height
+        })
+    }
+
+    /// Builds a grid of the same size with every cell mapped by `f`, which
+    /// also gets coordinates of the cell.
+    pub fn map<U>(&self, mut f: impl FnMut(Vec2<usize>, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(coords, cell)| f(coords, cell)).collect(),
+        }
+    }
+
+    fn index(&self, coords: Vec2<usize>) -> Option<usize> {
+        (coords.x() < self.width && coords.y() < self.height)
+            .then(|| coords.y() * self.width + coords.x())
+    }
+
+    fn coords(&self, i: usize) -> Vec2<usize> {
+        Vec2::new(i % self.width, i / self.width)
+    }
+}
+
+/// Renders the grid with a line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in &self.cells[y * self.width..(y + 1) * self.width] {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::Grid;
+    use crate::common::geometry::Vec2;
+
+    fn grid() -> Grid<u8> {
+        Grid::new(3, (0..6).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(Grid::new(4, vec![0; 6]).is_err());
+        assert!(Grid::<u8>::new(0, vec![]).is_err());
+    }
+
+    #[test]
+    fn test_filled() {
+        let grid = Grid::filled(2, 3, 'x');
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert!(grid.cells().all(|&cell| cell == 'x'));
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::filled(0, 2, 'x');
+        assert_eq!((grid.width(), grid.height()), (0, 2));
+        assert_eq!(grid.cells().count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+        assert_eq!(grid.neighbours8(Vec2::new(0, 0)).count(), 0);
+        assert_eq!(grid.neighbours4(Vec2::new(0, 0)).count(), 0);
+
+        let grid = Grid::filled(3, 0, 'x');
+        assert_eq!(grid.to_string(), "");
+        assert_eq!(grid.neighbours8(Vec2::new(1, 0)).count(), 0);
+        assert_eq!(grid.get(Vec2::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let map = "#..\n.#.\n";
+        let grid = Grid::parse(map, |c| match c {
+            '#' => Ok(1),
+            '.' => Ok(0),
+            _ => Err(anyhow!("Unknown cell")),
+        })
+        .unwrap();
+        assert_eq!(grid.get(Vec2::new(1, 1)), Some(&1));
+        assert_eq!(grid.to_string(), "100\n010\n");
+
+        assert!(Grid::parse("##\n#", |_| Ok(())).is_err());
+        assert!(Grid::parse("#?", |c| if c == '#' { Ok(()) } else { Err(anyhow!("?")) }).is_err());
+        assert!(Grid::parse("", |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = grid();
+        assert_eq!(grid.get(Vec2::new(2, 1)), Some(&5));
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, 2)), None);
+
+        *grid.get_mut(Vec2::new(1, 0)).unwrap() = 9;
+        assert_eq!(
+            grid.cells().copied().collect::<Vec<_>>(),
+            [0, 9, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbours4(Vec2::new(0, 0)).collect();
+        assert_eq!(corner, [Vec2::new(1, 0), Vec2::new(0, 1)]);
+        let middle: Vec<_> = grid.neighbours4(Vec2::new(1, 1)).collect();
+        assert_eq!(middle, [Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(2, 1)]);
+        assert_eq!(grid.neighbours4(Vec2::new(10, 10)).count(), 0);
+        let outside: Vec<_> = grid.neighbours4(Vec2::new(3, 1)).collect();
+        assert_eq!(outside, [Vec2::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbours8(Vec2::new(0, 0)).collect();
+        assert_eq!(corner, [Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(1, 1)]);
+        assert_eq!(grid.neighbours8(Vec2::new(1, 1)).count(), 5);
+        assert_eq!(grid.neighbours8(Vec2::new(10, 10)).count(), 0);
+        let outside: Vec<_> = grid.neighbours8(Vec2::new(3, 1)).collect();
+        assert_eq!(outside, [Vec2::new(2, 0), Vec2::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_map() {
+        let sums = grid().map(|coords, cell| coords.x() + coords.y() + usize::from(*cell));
+        assert_eq!(sums.get(Vec2::new(2, 1)), Some(&8));
+    }
+}
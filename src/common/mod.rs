@@ -0,0 +1,8 @@
+//! Utilities shared by the solutions of all years.
+
+pub mod aabb;
+pub mod dot;
+pub mod geometry;
+pub mod grid;
+pub mod parsing;
+pub mod search;
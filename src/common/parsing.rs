@@ -0,0 +1,36 @@
+//! Parser combinators used by the puzzle input parsers.
+
+use winnow::{
+    Parser,
+    ascii::space0,
+    combinator::delimited,
+    error::ParserError,
+    stream::{AsChar, Stream, StreamIsPartial},
+};
+
+/// Creates a new [`Parser`] that accepts and trims any number of
+/// whitespace characters around the provided `parser` and returns its
+/// result.
+pub fn ws<I, O, E>(parser: impl Parser<I, O, E>) -> impl Parser<I, O, E>
+where
+    I: Stream + StreamIsPartial,
+    I::Token: AsChar + Clone,
+    E: ParserError<I>,
+{
+    delimited(space0, parser, space0)
+}
+
+#[cfg(test)]
+mod tests {
+    use winnow::{Parser, ascii::alpha1};
+
+    use super::ws;
+
+    #[test]
+    fn test_ws() {
+        let mut parser = ws::<_, _, winnow::error::ContextError>(alpha1);
+        assert_eq!(parser.parse("  abc \t"), Ok("abc"));
+        assert_eq!(parser.parse("abc"), Ok("abc"));
+        assert!(parser.parse(" a b ").is_err());
+    }
+}
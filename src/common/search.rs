@@ -0,0 +1,93 @@
+//! Searching for shortest paths in graphs given by a successor function.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Finds the smallest number of steps from `start` to a node accepted by
+/// `is_goal` using breadth-first search. Returns the reached node together
+/// with the number of steps, or `None` if no goal is reachable.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some((node, steps));
+        }
+        for next in successors(&node) {
+            if visited.insert(next.clone()) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Finds the cheapest path from `start` to a node accepted by `is_goal` using
+/// Dijkstra's algorithm, where `successors` returns the neighbours of a node
+/// together with the costs of moving to them. Returns the reached node
+/// together with the total cost, or `None` if no goal is reachable.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(N, C)>
+where
+    N: Clone + Ord + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = BinaryHeap::from([Reverse((C::default(), start))]);
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((node, cost));
+        }
+        for (next, step) in successors(&node) {
+            if !visited.contains(&next) {
+                queue.push(Reverse((cost + step, next)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bfs, dijkstra};
+
+    #[test]
+    fn test_bfs() {
+        // Reaching 10 from 1 by either doubling or incrementing.
+        let successors = |&n: &u32| [n * 2, n + 1].into_iter().filter(|&n| n <= 10);
+        assert_eq!(bfs(1, successors, |&n| n == 10), Some((10, 4)));
+        assert_eq!(bfs(1, successors, |&n| n == 11), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = [('a', 'b', 7), ('a', 'c', 2), ('c', 'b', 3), ('b', 'd', 1)];
+        let successors = |&node: &char| {
+            edges
+                .iter()
+                .filter(move |(from, _, _)| *from == node)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+        assert_eq!(dijkstra('a', successors, |&n| n == 'd'), Some(('d', 6)));
+        assert_eq!(dijkstra('d', successors, |&n| n == 'a'), None);
+    }
+}
@@ -0,0 +1,219 @@
+//! Examples from the puzzle descriptions, used to test the solutions.
+//!
+//! Examples of a puzzle live in a directory named after it, e.g.
+//! `examples/y15_d07/`. Each example consists of an input file `NAME.txt`
+//! and a sidecar `NAME.toml` with the expected answers of one or both parts:
+//!
+//! ```toml
+//! p1 = 605
+//! p2 = 982
+//! ```
+//!
+//! Examples of puzzles with parameters, like the duration of the race in
+//! 2015 day 14, set them in a `solver` table overriding the fields of the
+//! default solver:
+//!
+//! ```toml
+//! p1 = 1120
+//!
+//! [solver]
+//! duration = 1000
+//! ```
+//!
+//! Examples written by hand rather than taken from the puzzle description
+//! are named `handmade-NAME`, and their sidecar explains where the expected
+//! answers come from.
+//!
+//! Every puzzle must have examples, unless it is listed in
+//! [`WITHOUT_EXAMPLES`].
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use crate::{
+    answers::parse_parts,
+    input::puzzle_name,
+    solution::{Part, Puzzle},
+};
+
+/// A single example input together with its expected answers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    /// Expected answers in their textual form, keyed by part.
+    pub expected: BTreeMap<Part, String>,
+    /// Fields of the solver overriding its defaults, empty if the default
+    /// solver solves the example.
+    pub solver: Table,
+}
+
+/// Puzzles, as years and days, whose descriptions have no examples with
+/// answers the solutions can be checked against.
+pub const WITHOUT_EXAMPLES: &[(u16, u8)] = &[
+    // The examples show single rounds, but the answers are lengths after
+    // 40 and 50 rounds.
+    (2015, 10),
+    // There is a single Aunt Sue to find, in the input only.
+    (2015, 16),
+    // The example fight only shows who wins, not the gold spent.
+    (2015, 21),
+];
+
+/// Returns the directory with the examples of the puzzle from the given
+/// `year` and `day`.
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(puzzle_name(year, day))
+}
+
+impl Example {
+    /// Loads all examples from the `dir`, ordered by name. A missing
+    /// directory means there are no examples.
+    pub fn load_all(dir: &Path) -> Result<Vec<Self>> {
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+        let mut examples = vec![];
+        for entry in fs::read_dir(dir)
+            .with_context(|| format!("Failed to read examples from {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                examples.push(Self::load(&path)?);
+            }
+        }
+        examples.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(examples)
+    }
+
+    /// Loads the example with the input at `path` and the answers from its
+    /// sidecar file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let name = path
+            .file_stem()
+            .context("Example must have a file name")?
+            .to_string_lossy()
+            .into_owned();
+        let input = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read example {}", path.display()))?;
+        let sidecar = path.with_extension("toml");
+        let expected = fs::read_to_string(&sidecar)
+            .with_context(|| format!("Failed to read answers {}", sidecar.display()))?;
+        let (expected, solver) = parse_sidecar(&expected)
+            .with_context(|| format!("Invalid answers {}", sidecar.display()))?;
+        Ok(Self {
+            name,
+            input,
+            expected,
+            solver,
+        })
+    }
+}
+
+/// Splits the contents of a sidecar file into the expected answers and the
+/// fields of the solver.
+fn parse_sidecar(sidecar: &str) -> Result<(BTreeMap<Part, String>, Table)> {
+    let mut table: Table = sidecar.parse()?;
+    let solver = match table.remove("solver") {
+        Some(Value::Table(solver)) => solver,
+        Some(_) => bail!("Solver fields must be a table"),
+        None => Table::new(),
+    };
+    Ok((parse_parts(&table)?, solver))
+}
+
+/// Solves all examples of the puzzle solved by `S` and panics, listing every
+/// mismatch, if any answer differs from the expected one.
+///
+/// Also panics if the puzzle has no examples, unless it's listed in
+/// [`WITHOUT_EXAMPLES`], so a missing directory can't pass unnoticed.
+pub fn check<S>()
+where
+    S: Puzzle + Default + DeserializeOwned,
+{
+    let default = S::default();
+    let (year, day) = (default.year(), default.day());
+    let dir = dir(year, day);
+    let examples = Example::load_all(&dir).unwrap();
+    let exempt = WITHOUT_EXAMPLES.contains(&(year, day));
+    assert!(
+        !examples.is_empty() || exempt,
+        "No examples in {}, add some or list the puzzle in WITHOUT_EXAMPLES",
+        dir.display()
+    );
+    assert!(
+        examples.is_empty() || !exempt,
+        "Puzzle has examples in {}, remove it from WITHOUT_EXAMPLES",
+        dir.display()
+    );
+
+    let mut failures = vec![];
+    for example in &examples {
+        let solver = if example.solver.is_empty() {
+            None
+        } else {
+            match Value::Table(example.solver.clone()).try_into::<S>() {
+                Ok(solver) => Some(solver),
+                Err(err) => {
+                    failures.push(format!("{}: invalid solver fields: {err}", example.name));
+                    continue;
+                }
+            }
+        };
+        let puzzle: &dyn Puzzle = solver.as_ref().unwrap_or(&default);
+        for (&part, expected) in &example.expected {
+            match puzzle.solve(part, &example.input) {
+                Ok(answer) if answer.to_string() == *expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} part {}: expected {expected}, got {answer}",
+                    example.name,
+                    part.number()
+                )),
+                Err(err) => {
+                    failures.push(format!("{} part {}: {err:#}", example.name, part.number()))
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Example, dir, parse_sidecar};
+    use crate::solution::Part;
+
+    #[test]
+    fn test_load_all() {
+        let examples = Example::load_all(&dir(2015, 9)).unwrap();
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].name, "cities");
+        assert_eq!(examples[0].expected[&Part::One], "605");
+
+        assert!(Example::load_all(&dir(2015, 26)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_sidecar() {
+        let (expected, solver) = parse_sidecar("p1 = 4\n\n[solver]\nsteps = 4\n").unwrap();
+        assert_eq!(
+            expected.into_iter().collect::<Vec<_>>(),
+            [(Part::One, "4".to_owned())]
+        );
+        assert_eq!(solver["steps"].as_integer(), Some(4));
+
+        let (_, solver) = parse_sidecar("p2 = \"abc\"").unwrap();
+        assert!(solver.is_empty());
+        assert!(parse_sidecar("p1 = 1\nsolver = 3").is_err());
+        assert!(parse_sidecar("p3 = 1").is_err());
+    }
+}
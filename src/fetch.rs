@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::{
+    client::{Backend, Client, ClientArgs},
+    input::InputArgs,
+    output::{Format, print_json},
+};
+
+/// Downloads a puzzle input into the input directory, unless it's already
+/// there.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Year of the puzzle, e.g. `2015`.
+    #[arg(long)]
+    year: u16,
+
+    /// Day of the puzzle.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        input.ensure_input_dir("fetching inputs")?;
+
+        let path = input.day_path(self.year, self.day);
+        let downloaded = if path.exists() {
+            false
+        } else {
+            let client = self.client.client().await?;
+            fetch(&client, self.year, self.day, &path).await?;
+            true
+        };
+
+        match format {
+            Format::Text if downloaded => println!("Downloaded {}", path.display()),
+            Format::Text => println!("Already cached in {}", path.display()),
+            Format::Json => print_json([Fetched {
+                year: self.year,
+                day: self.day,
+                path,
+                downloaded,
+            }])?,
+        }
+        Ok(())
+    }
+}
+
+/// Machine-readable result of fetching an input.
+#[derive(Serialize)]
+struct Fetched {
+    year: u16,
+    day: u8,
+    path: PathBuf,
+    /// Whether the input was downloaded, rather than already cached.
+    downloaded: bool,
+}
+
+/// Downloads the input of the puzzle from the given `year` and `day` and
+/// saves it to the file at `path`.
+async fn fetch<B: Backend>(client: &Client<B>, year: u16, day: u8, path: &Path) -> Result<()> {
+    let input = client.input(year, day).await?;
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .with_context(|| format!("Failed to create input directory {}", dir.display()))?;
+    }
+    tokio::fs::write(path, input)
+        .await
+        .with_context(|| format!("Failed to write input file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use clap::Parser;
+
+    use super::{Args, fetch};
+    use crate::{
+        client::{Client, HttpBackend, tests::stub_server},
+        input::InputArgs,
+        output::Format,
+    };
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: Args,
+
+        #[command(flatten)]
+        input: InputArgs,
+    }
+
+    #[tokio::test]
+    async fn test_fetch() {
+        let (address, _) = stub_server(200, "iwrupvqb\n").await;
+        let client = Client::new(address, "secret", HttpBackend::new().unwrap());
+        let dir = env::temp_dir().join(format!("aoc-test-fetch-{}", std::process::id()));
+        let path = dir.join("y15_d04.txt");
+
+        fetch(&client, 2015, 4, &path).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "iwrupvqb\n");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_skip_cached() {
+        let (address, request) = stub_server(200, "downloaded\n").await;
+        let dir = env::temp_dir().join(format!("aoc-test-fetch-cached-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("y15_d04.txt");
+        std::fs::write(&path, "cached\n").unwrap();
+
+        let cli = Cli::parse_from([
+            "fetch",
+            "--year=2015",
+            "--day=4",
+            "--session=secret",
+            &format!("--base-url={address}"),
+            &format!("--input-dir={}", dir.display()),
+        ]);
+        cli.args.run(&cli.input, Format::Json).await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "cached\n");
+        let request = tokio::time::timeout(Duration::from_millis(100), request).await;
+        assert!(request.is_err(), "The server received a request");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, ensure};
+use tokio::io::AsyncReadExt;
+
+use crate::solution::Puzzle;
+
+/// Path which makes the input be read from the standard input.
+const STDIN_PATH: &str = "-";
+
+/// Options deciding where puzzle inputs are read from.
+#[derive(clap::Args, Debug, Clone)]
+pub struct InputArgs {
+    /// Path to the puzzle input file, or `-` to read it from the standard
+    /// input. Overrides the file from the input directory.
+    #[arg(long, global = true)]
+    input: Option<PathBuf>,
+
+    /// Directory containing puzzle inputs named like `y15_d07.txt`.
+    #[arg(long, global = true, env = "AOC_INPUT_DIR", default_value = "inputs")]
+    input_dir: PathBuf,
+}
+
+impl InputArgs {
+    /// Returns the path of the `puzzle` input file within the input
+    /// directory.
+    pub fn path(&self, puzzle: &dyn Puzzle) -> PathBuf {
+        self.day_path(puzzle.year(), puzzle.day())
+    }
+
+    /// Returns the path of the input file of the puzzle from the given
+    /// `year` and `day` within the input directory.
+    pub fn day_path(&self, year: u16, day: u8) -> PathBuf {
+        self.input_dir.join(file_name(year, day))
+    }
+
+    /// Fails if the input was explicitly provided, for commands working with
+    /// the input directory only. The `action` describes the command in the
+    /// error, e.g. `verifying puzzles`.
+    pub fn ensure_input_dir(&self, action: &str) -> Result<()> {
+        ensure!(
+            self.input.is_none(),
+            "--input can't be used when {action}, use --input-dir instead"
+        );
+        Ok(())
+    }
+
+    /// Reads the input of the `puzzle`, preferring the explicitly provided
+    /// input over the input directory.
+    pub async fn read(&self, puzzle: &dyn Puzzle) -> Result<String> {
+        match &self.input {
+            Some(path) if path == Path::new(STDIN_PATH) => {
+                let mut input = String::new();
+                tokio::io::stdin()
+                    .read_to_string(&mut input)
+                    .await
+                    .context("Failed to read input from stdin")?;
+                Ok(input)
+            }
+            Some(path) => read_file(path).await,
+            None => read_file(&self.path(puzzle)).await,
+        }
+    }
+}
+
+/// Short name of the puzzle from the given `year` and `day`, e.g. `y15_d07`.
+pub fn puzzle_name(year: u16, day: u8) -> String {
+    format!("y{:02}_d{:02}", year % 100, day)
+}
+
+/// Name of the input file for the puzzle from the given `year` and `day`.
+fn file_name(year: u16, day: u8) -> String {
+    format!("{}.txt", puzzle_name(year, day))
+}
+
+async fn read_file(path: &Path) -> Result<String> {
+    tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read input file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{InputArgs, file_name};
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        input: InputArgs,
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(2015, 7), "y15_d07.txt");
+        assert_eq!(file_name(2015, 21), "y15_d21.txt");
+    }
+
+    #[test]
+    fn test_ensure_input_dir() {
+        let args = Cli::parse_from(["aoc", "--input-dir", "elsewhere"]).input;
+        assert!(args.ensure_input_dir("verifying puzzles").is_ok());
+
+        let args = Cli::parse_from(["aoc", "--input", "-"]).input;
+        let err = args.ensure_input_dir("verifying puzzles").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "--input can't be used when verifying puzzles, use --input-dir instead"
+        );
+    }
+}
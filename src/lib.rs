@@ -1,24 +1,107 @@
-#![feature(
-    step_trait,
-    iterator_try_collect,
-    iter_array_chunks,
-    iterator_try_reduce
-)]
+#![feature(step_trait, iterator_try_collect)]
 
-pub mod y15;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod common;
+pub mod examples;
+pub mod fetch;
+pub mod input;
+pub mod nanos;
+pub mod output;
+pub mod registry;
+pub mod run;
+pub mod run_all;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod table;
+pub mod verify;
 
-use clap::Parser;
+/// Declares the modules of days solved in a year and generates a `register`
+/// function adding their solutions to a registry, together with a test per
+/// day checking its examples.
+macro_rules! define_year {
+    ($($day_num:ident),+) => {
+        $( mod $day_num; )*
 
+        /// Adds solutions of all days from this year to the `registry`.
+        pub fn register(registry: &mut $crate::registry::Registry) {
+            $( registry.register($day_num::Solver::default()); )*
+        }
+
+        /// Checks the solutions against the examples of every day.
+        #[cfg(test)]
+        mod examples {
+            $(
+                #[test]
+                fn $day_num() {
+                    $crate::examples::check::<super::$day_num::Solver>();
+                }
+            )*
+        }
+    };
+}
+
+/// Declares the modules of all years and generates a function adding their
+/// solutions to a registry.
+macro_rules! define_years {
+    ($($year:ident),+) => {
+        $( pub mod $year; )*
+
+        /// Adds solutions of all years to the `registry`.
+        fn register_years(registry: &mut registry::Registry) {
+            $( $year::register(registry); )*
+        }
+    };
+}
+
+define_years!(y15);
+
+use clap::{Parser, Subcommand};
+
+use crate::{input::InputArgs, output::Format};
+
+/// Solutions of the Advent of Code puzzles.
 #[derive(Parser)]
 #[command(name = "aoc")]
-pub enum Cli {
+pub struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Format of the printed results.
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    Run(run::Args),
     Y15(y15::Args),
+    RunAll(run_all::Args),
+    Verify(verify::Args),
+    Bench(bench::Args),
+    Fetch(fetch::Args),
+    Submit(submit::Args),
+    New(scaffold::Args),
 }
 
 impl Cli {
     pub async fn run(&self) -> anyhow::Result<()> {
-        match self {
-            Cli::Y15(args) => args.run().await,
+        match &self.command {
+            Command::Run(args) => args.run(&self.input, self.format).await,
+            Command::Y15(args) => args.run(&self.input, self.format).await,
+            Command::RunAll(args) => args.run(&self.input, self.format).await,
+            Command::Verify(args) => args.run(&self.input, self.format).await,
+            Command::Bench(args) => args.run(&self.input, self.format).await,
+            Command::Fetch(args) => args.run(&self.input, self.format).await,
+            Command::Submit(args) => args.run(&self.input, self.format).await,
+            Command::New(args) => args.run(&self.input, self.format).await,
         }
     }
 }
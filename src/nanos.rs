@@ -0,0 +1,16 @@
+//! Serializes [`Duration`]s as whole nanoseconds, for use with
+//! `#[serde(with = "crate::nanos")]`.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    u64::try_from(duration.as_nanos())
+        .map_err(serde::ser::Error::custom)?
+        .serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+}
@@ -0,0 +1,24 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+use serde::Serialize;
+
+/// Format of the results printed by the commands.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable text.
+    #[default]
+    Text,
+    /// JSON Lines, with a single JSON object per result.
+    Json,
+}
+
+/// Prints the `records` to the standard output as JSON Lines.
+pub fn print_json<T: Serialize>(records: impl IntoIterator<Item = T>) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    for record in records {
+        serde_json::to_writer(&mut stdout, &record)?;
+        writeln!(stdout)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{register_years, solution::Puzzle};
+
+/// A collection of all puzzles solved so far.
+pub struct Registry {
+    puzzles: Vec<Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    /// Creates an empty registry.
+    pub fn empty() -> Self {
+        Self { puzzles: vec![] }
+    }
+
+    /// Creates a registry containing solutions of all years.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        register_years(&mut registry);
+        registry
+    }
+
+    /// Adds the `puzzle` to the registry, keeping it ordered by year and day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a puzzle for the same year and day is already registered.
+    pub fn register(&mut self, puzzle: impl Puzzle + 'static) {
+        let key = (puzzle.year(), puzzle.day());
+        match self
+            .puzzles
+            .binary_search_by_key(&key, |p| (p.year(), p.day()))
+        {
+            Ok(_) => panic!("Puzzle {}/{} registered twice", key.0, key.1),
+            Err(index) => self.puzzles.insert(index, Box::new(puzzle)),
+        }
+    }
+
+    /// Returns the puzzle from the given `year` and `day`, if it was solved.
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn Puzzle> {
+        self.iter().find(|p| p.year() == year && p.day() == day)
+    }
+
+    /// Creates an iterator over all registered puzzles, ordered by year and day.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+        self.puzzles.iter().map(|p| p.as_ref())
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Registry;
+
+    #[test]
+    fn test_all_days_registered_in_order() {
+        let registry = Registry::new();
+        let days: Vec<_> = registry.iter().map(|p| (p.year(), p.day())).collect();
+        let expected: Vec<_> = (1..=21).map(|day| (2015, day)).collect();
+        assert_eq!(days, expected);
+    }
+
+    #[test]
+    fn test_get() {
+        let registry = Registry::new();
+        assert_eq!(
+            registry.get(2015, 7).map(|p| p.title()),
+            Some("Some Assembly Required")
+        );
+        assert!(registry.get(2015, 25).is_none());
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::{Context, Result, ensure};
+
+use crate::{
+    input::InputArgs,
+    output::{Format, print_json},
+    registry::Registry,
+    runner::solve_part,
+    solution::{Part, Puzzle},
+};
+
+/// Solves a single part of a puzzle, e.g. `aoc run 2015 7 1`.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Year of the puzzle, e.g. `2015`.
+    year: u16,
+
+    /// Day of the puzzle.
+    day: u8,
+
+    /// Part of the puzzle, `1` or `2`.
+    part: Part,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        run_part(self.year, self.day, self.part, input, format).await
+    }
+}
+
+/// Solves the `part` of the puzzle from the given `year` and `day` and prints
+/// the answer.
+pub async fn run_part(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &InputArgs,
+    format: Format,
+) -> Result<()> {
+    let registry = Registry::new();
+    let puzzle = registry
+        .get(year, day)
+        .with_context(|| format!("Day {day} of {year} is not solved yet"))?;
+    print_part(puzzle, part, input, format).await
+}
+
+/// Solves the `part` of the `puzzle` and prints the answer.
+pub async fn print_part(
+    puzzle: &dyn Puzzle,
+    part: Part,
+    input: &InputArgs,
+    format: Format,
+) -> Result<()> {
+    let input = input.read(puzzle).await;
+    let run = solve_part(puzzle, part, &input);
+    match format {
+        Format::Text => println!("Answer: {}", run.result?),
+        Format::Json => {
+            print_json([run.record()])?;
+            ensure!(run.is_ok(), "Failed to solve the puzzle");
+        }
+    }
+    Ok(())
+}
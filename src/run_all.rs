@@ -0,0 +1,96 @@
+use anyhow::{bail, ensure};
+
+use crate::{
+    input::InputArgs,
+    output::{Format, print_json},
+    registry::Registry,
+    runner::{PuzzleFilter, Run, run_puzzle},
+    table::Table,
+};
+
+/// Runs both parts of every solved puzzle and prints a summary.
+#[derive(clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    filter: PuzzleFilter,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        input.ensure_input_dir("running multiple puzzles")?;
+
+        let registry = Registry::new();
+        let mut runs = vec![];
+        for puzzle in self.filter.apply(&registry) {
+            runs.extend(run_puzzle(puzzle, input).await);
+        }
+        ensure!(!runs.is_empty(), "No puzzles match the given filters");
+
+        match format {
+            Format::Text => print!("{}", summary(&runs)),
+            Format::Json => print_json(runs.iter().map(Run::record))?,
+        }
+
+        let failed = runs.iter().filter(|run| !run.is_ok()).count();
+        if failed > 0 {
+            bail!("{failed} of {} parts failed", runs.len());
+        }
+        Ok(())
+    }
+}
+
+/// Creates a summary table of the runs, with a row per part.
+fn summary(runs: &[Run]) -> Table<5> {
+    let mut table = Table::new(["Year", "Day", "Part", "Time", "Answer"]);
+    for run in runs {
+        table.push([
+            run.year.to_string(),
+            run.day.to_string(),
+            run.part.number().to_string(),
+            format!("{:.2?}", run.elapsed),
+            match &run.result {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("FAILED: {err:#}"),
+            },
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use super::summary;
+    use crate::{answer::Answer, runner::Run, solution::Part};
+
+    #[test]
+    fn test_summary() {
+        let runs = [
+            Run {
+                year: 2015,
+                day: 1,
+                part: Part::One,
+                result: Ok(Answer::Int(232)),
+                elapsed: Duration::from_micros(15),
+            },
+            Run {
+                year: 2015,
+                day: 12,
+                part: Part::Two,
+                result: Err(anyhow!("Broken")),
+                elapsed: Duration::from_millis(3),
+            },
+        ];
+        assert_eq!(
+            summary(&runs).to_string(),
+            concat!(
+                "Year  Day  Part     Time  Answer\n",
+                "2015    1     1  15.00µs  232\n",
+                "2015   12     2   3.00ms  FAILED: Broken\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{
+    answer::Answer,
+    input::InputArgs,
+    registry::Registry,
+    solution::{Part, Puzzle},
+};
+
+/// Options selecting a subset of the solved puzzles.
+#[derive(clap::Args, Debug, Clone)]
+pub struct PuzzleFilter {
+    /// Selects only puzzles from the given year, e.g. `2015`.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Selects only puzzles from the given day.
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+impl PuzzleFilter {
+    /// Checks if the `puzzle` passes the year and day filters.
+    pub fn matches(&self, puzzle: &dyn Puzzle) -> bool {
+        self.year.is_none_or(|year| year == puzzle.year())
+            && self.day.is_none_or(|day| day == puzzle.day())
+    }
+
+    /// Creates an iterator over puzzles from the `registry` passing the filter.
+    pub fn apply<'a>(&self, registry: &'a Registry) -> impl Iterator<Item = &'a dyn Puzzle> {
+        registry.iter().filter(|puzzle| self.matches(*puzzle))
+    }
+}
+
+/// Outcome of solving a single part of a puzzle.
+#[derive(Debug)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub result: anyhow::Result<Answer>,
+    /// Time it took to parse the input and solve the part.
+    pub elapsed: Duration,
+}
+
+impl Run {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Creates a machine-readable record of the run.
+    pub fn record(&self) -> Record<'_> {
+        Record {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            answer: self.result.as_ref().ok(),
+            duration: self.elapsed,
+            error: self.result.as_ref().err().map(|err| format!("{err:#}")),
+        }
+    }
+}
+
+/// Machine-readable form of a [`Run`], printed with `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<&'a Answer>,
+    /// Time it took to parse the input and solve the part, in nanoseconds.
+    #[serde(with = "crate::nanos")]
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+/// Reads the input of the `puzzle` and solves both of its parts.
+///
+/// Failing to read the input doesn't stop the runner, but is reported as
+/// a failure of both parts instead.
+pub async fn run_puzzle(puzzle: &dyn Puzzle, input: &InputArgs) -> [Run; 2] {
+    let input = input.read(puzzle).await;
+    [Part::One, Part::Two].map(|part| solve_part(puzzle, part, &input))
+}
+
+/// Solves the `part` of the `puzzle` with an already read `input`.
+///
+/// If reading the input failed, the run fails with the same error.
+pub fn solve_part(puzzle: &dyn Puzzle, part: Part, input: &anyhow::Result<String>) -> Run {
+    let start = Instant::now();
+    let result = match input {
+        Ok(input) => puzzle.solve(part, input),
+        Err(err) => Err(anyhow::anyhow!("{err:#}")),
+    };
+    Run {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        part,
+        result,
+        elapsed: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use super::Run;
+    use crate::{answer::Answer, solution::Part};
+
+    #[test]
+    fn test_record() {
+        let mut run = Run {
+            year: 2015,
+            day: 11,
+            part: Part::Two,
+            result: Ok(Answer::from("vzcaabcc")),
+            elapsed: Duration::from_micros(15),
+        };
+        assert_eq!(
+            serde_json::to_string(&run.record()).unwrap(),
+            r#"{"year":2015,"day":11,"part":2,"answer":"vzcaabcc","duration":15000,"error":null}"#
+        );
+
+        run.result = Err(anyhow!("Broken"));
+        assert_eq!(
+            serde_json::to_string(&run.record()).unwrap(),
+            r#"{"year":2015,"day":11,"part":2,"answer":null,"duration":15000,"error":"Broken"}"#
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail, ensure};
+use serde::Serialize;
+
+use crate::{
+    input::{InputArgs, puzzle_name},
+    output::{Format, print_json},
+};
+
+/// Template of a new day module.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+/// Template of a new year module.
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs.tmpl");
+
+/// Maximum line width used by rustfmt, kept when editing the day list.
+const MAX_WIDTH: usize = 100;
+
+/// Creates a module for a new day from a template, registers it and adds an
+/// empty input and example for it.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Year of the puzzle, e.g. `2015`.
+    #[arg(long)]
+    year: u16,
+
+    /// Day of the puzzle.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Title of the puzzle, `Day N` when not given.
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Root directory of the crate to add the day to.
+    #[arg(long, default_value = env!("CARGO_MANIFEST_DIR"))]
+    root: PathBuf,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        input.ensure_input_dir("creating a day")?;
+
+        let title = match &self.title {
+            Some(title) => title.clone(),
+            None => format!("Day {}", self.day),
+        };
+        let created = scaffold(
+            &self.root,
+            &input.day_path(self.year, self.day),
+            self.year,
+            self.day,
+            &title,
+        )?;
+
+        match format {
+            Format::Text => {
+                for path in &created {
+                    println!("Created {}", path.display());
+                }
+            }
+            Format::Json => print_json([Scaffolded {
+                year: self.year,
+                day: self.day,
+                created,
+            }])?,
+        }
+        Ok(())
+    }
+}
+
+/// Machine-readable result of creating a day.
+#[derive(Serialize)]
+struct Scaffolded {
+    year: u16,
+    day: u8,
+    created: Vec<PathBuf>,
+}
+
+/// Creates and registers the module of the day in the crate at `root`,
+/// together with an empty input at `input` and an empty example, and returns
+/// paths of the created files. The module of the year is created too, if it's
+/// the first day solved in that year.
+///
+/// Fails without changing anything if the module already exists. Existing
+/// input and example files are kept.
+fn scaffold(root: &Path, input: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>> {
+    let year_module = format!("y{:02}", year % 100);
+    let day_module = format!("d{day:02}");
+    let year_dir = root.join("src").join(&year_module);
+    let year_file = year_dir.join("mod.rs");
+    let day_file = year_dir.join(format!("{day_module}.rs"));
+    let example = root
+        .join("examples")
+        .join(puzzle_name(year, day))
+        .join("example.txt");
+
+    for existing in [&day_file, &year_dir.join(&day_module)] {
+        ensure!(
+            !existing.exists(),
+            "Day {day} of {year} already exists at {}",
+            existing.display()
+        );
+    }
+    // Registration of the day, or of the whole year if it's new.
+    let (registry_file, registered) = if year_file.exists() {
+        let source = read(&year_file)?;
+        let registered = register_module(&source, "define_year!", &day_module)
+            .with_context(|| format!("Failed to register the day in {}", year_file.display()))?;
+        (year_file.clone(), registered)
+    } else {
+        let lib_file = root.join("src/lib.rs");
+        let source = read(&lib_file)?;
+        let registered = register_module(&source, "define_years!", &year_module)
+            .with_context(|| format!("Failed to register the year in {}", lib_file.display()))?;
+        (lib_file, registered)
+    };
+
+    let mut created = vec![];
+    if !year_file.exists() {
+        let source = YEAR_TEMPLATE
+            .replace("{{DAY_MODULE}}", &day_module)
+            .replace("{{YEAR}}", &year.to_string());
+        fs::create_dir_all(&year_dir)
+            .with_context(|| format!("Failed to create directory {}", year_dir.display()))?;
+        create_new(&year_file, &source)?;
+        created.push(year_file);
+    }
+    let source = DAY_TEMPLATE
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE}}", &format!("{title:?}"));
+    create_new(&day_file, &source)?;
+    created.push(day_file);
+    fs::write(&registry_file, registered)
+        .with_context(|| format!("Failed to write {}", registry_file.display()))?;
+
+    for (path, content) in [
+        (input, ""),
+        (&example, ""),
+        (&example.with_extension("toml"), "# p1 = 0\n# p2 = 0\n"),
+    ] {
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+            }
+            create_new(path, content)?;
+            created.push(path.to_owned());
+        }
+    }
+    Ok(created)
+}
+
+/// Reads the file at `path` into a string.
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// Writes the `content` to a new file at `path`, failing if it exists.
+fn create_new(path: &Path, content: &str) -> Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .with_context(|| format!("Failed to create {}", path.display()))
+}
+
+/// Adds the `module` to the list of modules passed to the macro `name`, e.g.
+/// `define_year!`, in the `source` file, keeping the list sorted and
+/// formatted like rustfmt does.
+fn register_module(source: &str, name: &str, module: &str) -> Result<String> {
+    let invocation = format!("\n{name}(");
+    let start = source
+        .find(&invocation)
+        .with_context(|| format!("No {name} invocation"))?
+        + 1;
+    let end = start
+        + source[start..]
+            .find(");")
+            .with_context(|| format!("Unterminated {name} invocation"))?
+        + 2;
+
+    let mut modules: Vec<_> = source[start + invocation.len() - 1..end - 2]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect();
+    if modules.contains(&module) {
+        bail!("Module {module} is already registered");
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        format_invocation(name, &modules),
+        &source[end..]
+    ))
+}
+
+/// Formats the invocation of the macro `name` with the `modules`, either on a
+/// single line or with the modules wrapped into indented lines.
+fn format_invocation(name: &str, modules: &[&str]) -> String {
+    let single = format!("{name}({});", modules.join(", "));
+    if single.len() <= MAX_WIDTH {
+        return single;
+    }
+
+    let mut lines = vec![];
+    let mut line = String::new();
+    for (i, module) in modules.iter().enumerate() {
+        let item = if i + 1 < modules.len() {
+            format!("{module},")
+        } else {
+            module.to_string()
+        };
+        if !line.is_empty() && line.len() + 1 + item.len() > MAX_WIDTH {
+            lines.push(line);
+            line = String::new();
+        }
+        if line.is_empty() {
+            line.push_str("    ");
+        } else {
+            line.push(' ');
+        }
+        line.push_str(&item);
+    }
+    lines.push(line);
+    format!("{name}(\n{}\n);", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{register_module, scaffold};
+
+    #[test]
+    fn test_register_module() {
+        let source = "mod a;\n\ndefine_year!(d01, d03);\n\nconst YEAR: u16 = 2015;\n";
+        assert_eq!(
+            register_module(source, "define_year!", "d02").unwrap(),
+            "mod a;\n\ndefine_year!(d01, d02, d03);\n\nconst YEAR: u16 = 2015;\n"
+        );
+        assert!(register_module(source, "define_year!", "d03").is_err());
+        assert!(register_module(source, "define_years!", "y16").is_err());
+    }
+
+    #[test]
+    fn test_register_module_wrapped() {
+        let days: Vec<_> = (1..=21).map(|day| format!("d{day:02}")).collect();
+        let source = format!("\ndefine_year!({});\n", days.join(", "));
+        assert_eq!(
+            register_module(&source, "define_year!", "d22").unwrap(),
+            concat!(
+                "\n",
+                "define_year!(\n",
+                "    d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19,\n",
+                "    d20, d21, d22\n",
+                ");\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = env::temp_dir().join(format!("aoc-test-scaffold-{}", std::process::id()));
+        let year_dir = root.join("src/y15");
+        fs::create_dir_all(&year_dir).unwrap();
+        fs::write(year_dir.join("mod.rs"), "\ndefine_year!(d01);\n").unwrap();
+        fs::write(root.join("src/lib.rs"), "\ndefine_years!(y15);\n").unwrap();
+        let input = root.join("inputs/y15_d22.txt");
+
+        let created = scaffold(&root, &input, 2015, 22, "Wizard Simulator 20XX").unwrap();
+        assert_eq!(created.len(), 4);
+        let module = fs::read_to_string(year_dir.join("d22.rs")).unwrap();
+        assert!(module.contains("const DAY: u8 = 22;"));
+        assert!(module.contains(r#"const TITLE: &'static str = "Wizard Simulator 20XX";"#));
+        assert_eq!(
+            fs::read_to_string(year_dir.join("mod.rs")).unwrap(),
+            "\ndefine_year!(d01, d22);\n"
+        );
+        assert!(input.exists());
+        assert!(root.join("examples/y15_d22/example.toml").exists());
+
+        assert!(scaffold(&root, &input, 2015, 22, "Again").is_err());
+
+        let created = scaffold(&root, &root.join("inputs/y16_d01.txt"), 2016, 1, "New").unwrap();
+        assert!(created.contains(&root.join("src/y16/mod.rs")));
+        assert!(root.join("src/y16/d01.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "\ndefine_years!(y15, y16);\n"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+
+/// One of the two parts every puzzle consists of.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    clap::ValueEnum,
+    Serialize,
+    Deserialize,
+)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    #[value(name = "p1", alias = "1")]
+    One,
+    #[value(name = "p2", alias = "2")]
+    Two,
+}
+
+impl Part {
+    /// Number of the part, as used in the puzzle description.
+    pub fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        part.number()
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(number: u8) -> Result<Self> {
+        match number {
+            1 => Ok(Self::One),
+            2 => Ok(Self::Two),
+            _ => Err(anyhow!("Invalid part number {number}")),
+        }
+    }
+}
+
+/// A solution of a single Advent of Code puzzle.
+///
+/// The puzzle input is parsed once with [`parse`](Self::parse) and then shared
+/// by both parts, so implementors should keep all the reading and validation
+/// in there.
+pub trait Solution {
+    /// Parsed representation of the puzzle input. It may borrow from the raw
+    /// input text.
+    type Input<'a>;
+
+    /// Year of the event the puzzle comes from.
+    const YEAR: u16;
+
+    /// Day of the event, starting from 1.
+    const DAY: u8;
+
+    /// Title of the puzzle, as shown on the Advent of Code website.
+    const TITLE: &'static str;
+
+    /// Parses the raw puzzle `input`.
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+
+    /// Solves the first part of the puzzle.
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// Solves the second part of the puzzle.
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer>;
+}
+
+/// Object safe version of [`Solution`], implemented for every solution, so
+/// solutions of different days can be stored and run together.
+pub trait Puzzle: Send + Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Parses the raw `input` and solves the requested `part` of the puzzle.
+    ///
+    /// Trailing line breaks, like the one ending downloaded inputs, are
+    /// removed before parsing.
+    fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        self.solve_timed(part, input).map(|timed| timed.answer)
+    }
+
+    /// Same as [`solve`](Self::solve), but also measures how long parsing and
+    /// solving took.
+    fn solve_timed(&self, part: Part, input: &str) -> Result<Timed>;
+}
+
+/// An answer along with the time spent on each stage of finding it.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Send + Sync,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn solve_timed(&self, part: Part, input: &str) -> Result<Timed> {
+        let start = Instant::now();
+        let input = self.parse(input.trim_end_matches(['\n', '\r']))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => self.part1(&input),
+            Part::Two => self.part2(&input),
+        }?;
+        let solve = start.elapsed();
+
+        Ok(Timed {
+            answer,
+            parse,
+            solve,
+        })
+    }
+}
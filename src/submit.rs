@@ -0,0 +1,293 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{ClientArgs, Hint, Outcome},
+    input::InputArgs,
+    output::{Format, print_json},
+    registry::Registry,
+    solution::Part,
+};
+
+/// Solves a part of a puzzle and submits the answer to the website.
+#[derive(clap::Args)]
+pub struct Args {
+    /// Year of the puzzle, e.g. `2015`.
+    #[arg(long)]
+    year: u16,
+
+    /// Day of the puzzle.
+    #[arg(long)]
+    day: u8,
+
+    /// Part of the puzzle, `1` or `2`.
+    #[arg(long)]
+    part: Part,
+
+    /// JSON file with the history of submitted answers.
+    #[arg(long, env = "AOC_HISTORY", default_value = "submissions.json")]
+    history: PathBuf,
+
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        let registry = Registry::new();
+        let puzzle = registry
+            .get(self.year, self.day)
+            .with_context(|| format!("Day {} of {} is not solved yet", self.day, self.year))?;
+        let input = input.read(puzzle).await?;
+        let answer = puzzle.solve(self.part, &input)?.to_string();
+
+        let mut history = History::read(&self.history).await?;
+        history.check(self.year, self.day, self.part, &answer, now())?;
+
+        let client = self.client.client().await?;
+        let outcome = client
+            .submit(self.year, self.day, self.part, &answer)
+            .await?;
+        let attempt = Attempt {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            answer,
+            time: now(),
+            outcome,
+        };
+        history.attempts.push(attempt.clone());
+        history.write(&self.history).await?;
+
+        match format {
+            Format::Text => println!("{}", attempt.describe()),
+            Format::Json => print_json([&attempt])?,
+        }
+        if attempt.outcome != Outcome::Correct {
+            bail!("Answer {} was not accepted", attempt.answer);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    /// Time of the submission in seconds since the Unix epoch.
+    pub time: u64,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    fn is_for(&self, year: u16, day: u8, part: Part) -> bool {
+        self.year == year && self.day == day && self.part == part
+    }
+
+    /// Describes the outcome of the attempt for a human.
+    fn describe(&self) -> String {
+        let answer = &self.answer;
+        match &self.outcome {
+            Outcome::Correct => format!("Answer {answer} is correct"),
+            Outcome::Incorrect { hint, wait } => {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => " (too high)",
+                    Some(Hint::TooLow) => " (too low)",
+                    None => "",
+                };
+                format!("Answer {answer} is incorrect{hint}, wait {wait:?} before trying again")
+            }
+            Outcome::TooRecent { wait } => {
+                format!("Answer {answer} was submitted too early, wait {wait:?} and try again")
+            }
+            Outcome::WrongLevel => {
+                format!("Answer {answer} wasn't checked, the part is solved or still locked")
+            }
+            Outcome::Unknown => {
+                format!("Answer {answer} was submitted, but the response is unknown")
+            }
+        }
+    }
+}
+
+/// History of all submitted answers, used to avoid submitting the same
+/// wrong answer twice and to respect the cooldown between submissions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Reads the history from the file at `path`, or starts an empty one if
+    /// there is no such file yet.
+    pub async fn read(path: &Path) -> Result<Self> {
+        let content = match tokio::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read history {}", path.display()));
+            }
+        };
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid history {}", path.display()))
+    }
+
+    pub async fn write(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(path, content)
+            .await
+            .with_context(|| format!("Failed to write history {}", path.display()))
+    }
+
+    /// Checks if the `answer` can be submitted at the time `now`, given in
+    /// seconds since the Unix epoch.
+    ///
+    /// Fails if the part is already solved, the answer is known to be wrong,
+    /// or the cooldown after the previous submission hasn't passed yet.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str, now: u64) -> Result<()> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.is_for(year, day, part))
+        };
+        if let Some(correct) = attempts().find(|a| a.outcome == Outcome::Correct) {
+            bail!("Part is already solved with answer {}", correct.answer);
+        }
+        for attempt in attempts() {
+            let Outcome::Incorrect { hint, .. } = attempt.outcome else {
+                continue;
+            };
+            if attempt.answer == answer {
+                bail!("Answer {answer} was already submitted and is wrong");
+            }
+            let (Ok(answer), Ok(wrong)) = (answer.parse::<i128>(), attempt.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match hint {
+                Some(Hint::TooHigh) if answer >= wrong => {
+                    bail!("Answer {answer} is wrong, {wrong} was already too high")
+                }
+                Some(Hint::TooLow) if answer <= wrong => {
+                    bail!("Answer {answer} is wrong, {wrong} was already too low")
+                }
+                _ => {}
+            }
+        }
+
+        let ready_at = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.year == year && attempt.day == day)
+            .map(|attempt| attempt.time + attempt.outcome.wait().as_secs())
+            .max()
+            .unwrap_or(0);
+        if ready_at > now {
+            bail!(
+                "Cooldown is active, wait {:?} before submitting again",
+                Duration::from_secs(ready_at - now)
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Attempt, History};
+    use crate::{
+        client::{Hint, Outcome},
+        solution::Part,
+    };
+
+    fn attempt(part: Part, answer: &str, time: u64, outcome: Outcome) -> Attempt {
+        Attempt {
+            year: 2015,
+            day: 7,
+            part,
+            answer: answer.to_owned(),
+            time,
+            outcome,
+        }
+    }
+
+    fn incorrect(hint: Option<Hint>) -> Outcome {
+        Outcome::Incorrect {
+            hint,
+            wait: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let history = History {
+            attempts: vec![
+                attempt(Part::One, "3176", 0, Outcome::Correct),
+                attempt(Part::Two, "20000", 100, incorrect(Some(Hint::TooHigh))),
+                attempt(Part::Two, "10000", 200, incorrect(Some(Hint::TooLow))),
+                attempt(Part::Two, "abc", 300, incorrect(None)),
+            ],
+        };
+        let check = |part, answer| history.check(2015, 7, part, answer, 1000);
+
+        assert!(check(Part::One, "3176").is_err());
+        assert!(check(Part::Two, "20001").is_err());
+        assert!(check(Part::Two, "10000").is_err());
+        assert!(check(Part::Two, "abc").is_err());
+        assert!(check(Part::Two, "14710").is_ok());
+        assert!(history.check(2015, 8, Part::One, "3176", 1000).is_ok());
+    }
+
+    #[test]
+    fn test_check_cooldown() {
+        let history = History {
+            attempts: vec![attempt(
+                Part::One,
+                "1",
+                1000,
+                Outcome::TooRecent {
+                    wait: Duration::from_secs(30),
+                },
+            )],
+        };
+        assert!(history.check(2015, 7, Part::One, "2", 1029).is_err());
+        assert!(history.check(2015, 7, Part::One, "2", 1030).is_ok());
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let history = History {
+            attempts: vec![attempt(
+                Part::Two,
+                "20000",
+                100,
+                incorrect(Some(Hint::TooHigh)),
+            )],
+        };
+        let json = serde_json::to_string(&history).unwrap();
+        assert!(json.contains(r#""outcome":"incorrect","hint":"too_high""#));
+        let parsed: History = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.attempts[0].outcome, history.attempts[0].outcome);
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A plain text table with right aligned columns, except for the last one
+/// which is left aligned, as it usually holds free-form text.
+pub struct Table<const N: usize> {
+    header: [&'static str; N],
+    rows: Vec<[String; N]>,
+}
+
+impl<const N: usize> Table<N> {
+    pub fn new(header: [&'static str; N]) -> Self {
+        Self {
+            header,
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: [String; N]) {
+        self.rows.push(row);
+    }
+}
+
+impl<const N: usize> Display for Table<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut widths = self.header.map(|cell| cell.chars().count());
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let header = self.header.map(String::from);
+        for row in std::iter::once(&header).chain(&self.rows) {
+            for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+                if i + 1 == N {
+                    writeln!(f, "{cell}")?;
+                } else {
+                    write!(f, "{cell:>width$}  ")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn test_display() {
+        let mut table = Table::new(["Day", "Time", "Answer"]);
+        table.push(["1".into(), "15.00µs".into(), "232".into()]);
+        table.push(["12".into(), "3.00ms".into(), "FAILED: Broken".into()]);
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "Day     Time  Answer\n",
+                "  1  15.00µs  232\n",
+                " 12   3.00ms  FAILED: Broken\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, ensure};
+use serde::Serialize;
+
+use crate::{
+    answers::Answers,
+    input::InputArgs,
+    output::{Format, print_json},
+    registry::Registry,
+    runner::{PuzzleFilter, Record, Run, run_puzzle},
+    table::Table,
+};
+
+/// Runs solutions against the real inputs and compares their answers with the
+/// known correct ones.
+#[derive(clap::Args)]
+pub struct Args {
+    #[command(flatten)]
+    filter: PuzzleFilter,
+
+    /// TOML file with the known correct answers.
+    #[arg(long, env = "AOC_ANSWERS", default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        input.ensure_input_dir("verifying puzzles")?;
+
+        let answers = Answers::read(&self.answers).await?;
+        let registry = Registry::new();
+        let mut runs = vec![];
+        for puzzle in self.filter.apply(&registry) {
+            runs.extend(run_puzzle(puzzle, input).await);
+        }
+        ensure!(!runs.is_empty(), "No puzzles match the given filters");
+
+        let verdicts: Vec<_> = runs.iter().map(|run| Verdict::of(run, &answers)).collect();
+        let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+        let wrong = count(|v| matches!(v, Verdict::Incorrect { .. }));
+        let failed = count(|v| matches!(v, Verdict::Failed));
+
+        match format {
+            Format::Text => {
+                let mut table = Table::new(["Year", "Day", "Part", "Time", "Result"]);
+                for (run, verdict) in runs.iter().zip(&verdicts) {
+                    table.push([
+                        run.year.to_string(),
+                        run.day.to_string(),
+                        run.part.number().to_string(),
+                        format!("{:.2?}", run.elapsed),
+                        verdict.describe(run),
+                    ]);
+                }
+                print!("{table}");
+                println!(
+                    "\n{} correct, {wrong} incorrect, {failed} failed, {} unknown",
+                    count(|v| matches!(v, Verdict::Correct)),
+                    count(|v| matches!(v, Verdict::Unknown)),
+                );
+            }
+            Format::Json => {
+                print_json(
+                    runs.iter()
+                        .zip(&verdicts)
+                        .map(|(run, verdict)| VerifyRecord {
+                            run: run.record(),
+                            verdict,
+                        }),
+                )?
+            }
+        }
+
+        if wrong + failed > 0 {
+            bail!("Verification failed");
+        }
+        Ok(())
+    }
+}
+
+/// Machine-readable result of verifying a single part.
+#[derive(Serialize)]
+struct VerifyRecord<'a> {
+    #[serde(flatten)]
+    run: Record<'a>,
+    #[serde(flatten)]
+    verdict: &'a Verdict,
+}
+
+/// Outcome of comparing a [`Run`] with the known answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "verdict", rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// The part was solved, but there is no known answer to compare with.
+    Unknown,
+    /// The part couldn't be solved.
+    Failed,
+}
+
+impl Verdict {
+    pub fn of(run: &Run, answers: &Answers) -> Self {
+        let Ok(answer) = &run.result else {
+            return Self::Failed;
+        };
+        match answers.get(run.year, run.day, run.part) {
+            Some(expected) if expected == answer.to_string() => Self::Correct,
+            Some(expected) => Self::Incorrect {
+                expected: expected.to_owned(),
+            },
+            None => Self::Unknown,
+        }
+    }
+
+    /// Describes the verdict for a human, together with the answer of the
+    /// `run` it was made for.
+    fn describe(&self, run: &Run) -> String {
+        match (self, &run.result) {
+            (Self::Correct, Ok(answer)) => format!("ok: {answer}"),
+            (Self::Incorrect { expected }, Ok(answer)) => {
+                format!("INCORRECT: expected {expected}, got {answer}")
+            }
+            (Self::Unknown, Ok(answer)) => format!("unknown: {answer}"),
+            (_, Err(err)) => format!("FAILED: {err:#}"),
+            (Self::Failed, Ok(_)) => unreachable!("Successful run can't fail"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use super::{Verdict, VerifyRecord};
+    use crate::{answer::Answer, answers::Answers, runner::Run, solution::Part};
+
+    fn run(part: Part, result: anyhow::Result<Answer>) -> Run {
+        Run {
+            year: 2015,
+            day: 1,
+            part,
+            result,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        let answers: Answers = "[2015.1]\np1 = 232\n".parse().unwrap();
+
+        let correct = run(Part::One, Ok(Answer::Int(232)));
+        assert_eq!(Verdict::of(&correct, &answers), Verdict::Correct);
+
+        let incorrect = run(Part::One, Ok(Answer::Int(-232)));
+        assert_eq!(
+            Verdict::of(&incorrect, &answers),
+            Verdict::Incorrect {
+                expected: "232".into()
+            }
+        );
+
+        let unknown = run(Part::Two, Ok(Answer::Int(1783)));
+        assert_eq!(Verdict::of(&unknown, &answers), Verdict::Unknown);
+
+        let failed = run(Part::One, Err(anyhow!("Broken")));
+        assert_eq!(Verdict::of(&failed, &answers), Verdict::Failed);
+    }
+
+    #[test]
+    fn test_record() {
+        let run = run(Part::One, Ok(Answer::Int(-232)));
+        let record = VerifyRecord {
+            run: run.record(),
+            verdict: &Verdict::Incorrect {
+                expected: "232".into(),
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            concat!(
+                r#"{"year":2015,"day":1,"part":1,"answer":-232,"duration":0,"error":null,"#,
+                r#""verdict":"incorrect","expected":"232"}"#
+            )
+        );
+    }
+}
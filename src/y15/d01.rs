@@ -1,5 +1,7 @@
 use anyhow::Context;
 
+use crate::{answer::Answer, solution::Solution};
+
 const INITIAL_FLOOR: i32 = 0;
 
 const UP_CHAR: char = '(';
@@ -18,24 +20,35 @@ pub fn traverse_apartment(directions: &str) -> impl Iterator<Item = i32> {
     })
 }
 
-pub async fn p1() -> anyhow::Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d01.txt").await?;
-    let answer = traverse_apartment(&input)
-        .last()
-        .context("Directions are empty")?;
-    println!("Answer: {}", answer);
-    Ok(())
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = &'a str;
 
-pub async fn p2() -> anyhow::Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d01.txt").await?;
-    let answer = traverse_apartment(&input)
-        .enumerate()
-        .find(|(_, floor)| *floor == -1)
-        .map(|(i, _)| i)
-        .context("Basement never entered");
-    println!("Answer: {}", answer.unwrap() + 1);
-    Ok(())
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<&'a str> {
+        Ok(input)
+    }
+
+    fn part1(&self, input: &&str) -> anyhow::Result<Answer> {
+        let answer = traverse_apartment(input)
+            .last()
+            .context("Directions are empty")?;
+        Ok(answer.into())
+    }
+
+    fn part2(&self, input: &&str) -> anyhow::Result<Answer> {
+        let answer = traverse_apartment(input)
+            .enumerate()
+            .find(|(_, floor)| *floor == -1)
+            .map(|(i, _)| i)
+            .context("Basement never entered")?;
+        Ok((answer + 1).into())
+    }
 }
 
 #[cfg(test)]
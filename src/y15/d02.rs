@@ -1,96 +1,55 @@
-use std::str::FromStr;
-
-use anyhow::Context;
-use futures::TryStreamExt;
-use tokio::{
-    fs::File,
-    io::{AsyncBufReadExt, BufReader},
+use crate::{
+    answer::Answer,
+    common::geometry::{Vec2, Vec3},
+    solution::Solution,
 };
-use tokio_stream::wrappers::LinesStream;
-
-use super::Vec2;
 
-struct Vec3 {
-    x: i32,
-    y: i32,
-    z: i32,
+fn surface_area(dims: &Vec3<i32>) -> i32 {
+    2 * sides(dims).map(|side| side.area()).iter().sum::<i32>()
 }
 
-impl FromStr for Vec3 {
-    type Err = anyhow::Error;
+fn sides(dims: &Vec3<i32>) -> [Vec2<i32>; 3] {
+    [
+        Vec2::new(dims.x(), dims.y()),
+        Vec2::new(dims.y(), dims.z()),
+        Vec2::new(dims.x(), dims.z()),
+    ]
+}
 
-    fn from_str(s: &str) -> anyhow::Result<Self> {
-        let mut s = s.split("x");
-        Ok(Vec3 {
-            x: s.next().context("Dimension must have 3 parts")?.parse()?,
-            y: s.next().context("Dimension must have 3 parts")?.parse()?,
-            z: s.next().context("Dimension must have 3 parts")?.parse()?,
-        })
-    }
+fn answer(dims: &[Vec3<i32>], f: impl Fn(i32, &Vec3<i32>) -> i32) -> anyhow::Result<Answer> {
+    let answer = dims.iter().fold(0, f);
+    Ok(answer.into())
 }
 
-impl Vec3 {
-    fn surface_area(&self) -> i32 {
-        let xy = self.x * self.y;
-        let yz = self.y * self.z;
-        let xz = self.x * self.z;
-        2 * (xy + yz + xz)
-    }
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
 
-    fn volume(&self) -> i32 {
-        self.x * self.y * self.z
-    }
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec3<i32>>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
 
-    fn sides(&self) -> [Vec2<i32>; 3] {
-        [
-            Vec2 {
-                x: self.x,
-                y: self.y,
-            },
-            Vec2 {
-                x: self.y,
-                y: self.z,
-            },
-            Vec2 {
-                x: self.x,
-                y: self.z,
-            },
-        ]
+    /// Reads dimensions of the presents in the `LxWxH` format.
+    fn parse(&self, input: &str) -> anyhow::Result<Vec<Vec3<i32>>> {
+        input.lines().map(str::parse).try_collect()
     }
-}
 
-async fn answer(f: impl Fn(i32, Vec3) -> i32) -> anyhow::Result<()> {
-    let input = File::open("inputs/y15_d02.txt").await?;
-    let input = BufReader::new(input);
-    let answer = LinesStream::new(input.lines())
-        .map_err(anyhow::Error::from)
-        .try_fold(0, {
-            async |acc, line| {
-                let dims = Vec3::from_str(&line)?;
-                Ok(f(acc, dims))
-            }
+    fn part1(&self, input: &Vec<Vec3<i32>>) -> anyhow::Result<Answer> {
+        answer(input, |acc, dims| {
+            acc + surface_area(dims) + sides(dims).map(|side| side.area()).iter().min().unwrap()
         })
-        .await?;
-    println!("Answer: {}", answer);
-    Ok(())
-}
-
-pub async fn p1() -> anyhow::Result<()> {
-    answer(|acc, dims| {
-        acc + dims.surface_area() + dims.sides().map(|side| side.area()).iter().min().unwrap()
-    })
-    .await
-}
+    }
 
-pub async fn p2() -> anyhow::Result<()> {
-    answer(|acc, dims| {
-        acc + dims.volume()
-            + dims
-                .sides()
-                .map(|side| side.perimeter())
-                .iter()
-                .min()
-                .unwrap()
-    })
-    .await
+    fn part2(&self, input: &Vec<Vec3<i32>>) -> anyhow::Result<Answer> {
+        answer(input, |acc, dims| {
+            acc + dims.volume()
+                + sides(dims)
+                    .map(|side| side.perimeter())
+                    .iter()
+                    .min()
+                    .unwrap()
+        })
+    }
 }
@@ -1,10 +1,11 @@
-use std::{collections::HashSet, ops::AddAssign};
+use std::collections::HashSet;
 
 use anyhow::anyhow;
 
-use super::Vec2;
+use crate::{answer::Answer, common::geometry::Vec2, solution::Solution};
 
-enum Direction {
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
     North,
     South,
     East,
@@ -25,13 +26,14 @@ impl TryFrom<char> for Direction {
     }
 }
 
-impl AddAssign<Direction> for Vec2<i32> {
-    fn add_assign(&mut self, rhs: Direction) {
-        match rhs {
-            Direction::North => self.y += 1,
-            Direction::South => self.y -= 1,
-            Direction::East => self.x += 1,
-            Direction::West => self.x -= 1,
+impl Direction {
+    /// Unit vector pointing in the direction.
+    fn offset(self) -> Vec2<i32> {
+        match self {
+            Direction::North => Vec2::<i32>::NORTH,
+            Direction::South => Vec2::<i32>::SOUTH,
+            Direction::East => Vec2::<i32>::EAST,
+            Direction::West => Vec2::<i32>::WEST,
         }
     }
 }
@@ -63,31 +65,40 @@ impl State {
 
     fn translate(&mut self, dir: Direction) {
         let current = &mut self.current[self.current_index];
-        *current += dir;
+        *current += dir.offset();
         self.visited.insert(*current);
 
         self.current_index = self.current_index.wrapping_add(1) % self.current.len();
     }
 }
 
-async fn answer(state: State) -> anyhow::Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d03.txt").await?;
-    let state =
-        input
-            .chars()
-            .map(Direction::try_from)
-            .try_fold(state, |mut state, dir| {
-                state.translate(dir?);
-                Ok::<_, anyhow::Error>(state)
-            })?;
-    println!("Answer: {}", state.visited.len());
-    Ok(())
+fn answer(directions: &[Direction], state: State) -> anyhow::Result<Answer> {
+    let state = directions.iter().fold(state, |mut state, dir| {
+        state.translate(*dir);
+        state
+    });
+    Ok(state.visited.len().into())
 }
 
-pub async fn p1() -> anyhow::Result<()> {
-    answer(State::new(1)).await
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Direction>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+
+    fn parse(&self, input: &str) -> anyhow::Result<Vec<Direction>> {
+        input.chars().map(Direction::try_from).try_collect()
+    }
+
+    fn part1(&self, input: &Vec<Direction>) -> anyhow::Result<Answer> {
+        answer(input, State::new(1))
+    }
 
-pub async fn p2() -> anyhow::Result<()> {
-    answer(State::new(2)).await
+    fn part2(&self, input: &Vec<Direction>) -> anyhow::Result<Answer> {
+        answer(input, State::new(2))
+    }
 }
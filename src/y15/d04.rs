@@ -1,29 +1,54 @@
 use anyhow::Context;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
-const INPUT: &str = "iwrupvqb";
+use crate::{answer::Answer, solution::Solution};
 
-fn find_suffix_for_md5_prefix(prefix: &str) -> Option<u32> {
+fn find_suffix_for_md5_prefix(key: &str, prefix: &str) -> Option<u32> {
     (0..u32::MAX)
         .into_par_iter()
         .by_exponential_blocks()
-        .find_first(|i| {
-            format!("{:x}", md5::compute(format!("{}{}", INPUT, i))).starts_with(prefix)
-        })
+        .find_first(|i| format!("{:x}", md5::compute(format!("{}{}", key, i))).starts_with(prefix))
 }
 
-fn answer(prefix: &str) -> anyhow::Result<()> {
-    let suffix = find_suffix_for_md5_prefix(prefix).context("Suffix not found")?;
+fn answer(key: &str, prefix: &str) -> anyhow::Result<Answer> {
+    let suffix = find_suffix_for_md5_prefix(key, prefix).context("Suffix not found")?;
 
-    println!("Answer: {}", suffix);
-
-    Ok(())
+    Ok(suffix.into())
 }
 
-pub async fn p1() -> anyhow::Result<()> {
-    answer("00000")
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = &'a str;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<&'a str> {
+        Ok(input.trim())
+    }
+
+    fn part1(&self, input: &&str) -> anyhow::Result<Answer> {
+        answer(input, "00000")
+    }
+
+    fn part2(&self, input: &&str) -> anyhow::Result<Answer> {
+        answer(input, "000000")
+    }
 }
 
-pub async fn p2() -> anyhow::Result<()> {
-    answer("000000")
+#[cfg(test)]
+mod tests {
+    use super::find_suffix_for_md5_prefix;
+
+    #[test]
+    fn test_find_suffix_for_md5_prefix() {
+        assert_eq!(find_suffix_for_md5_prefix("abcdef", "00000"), Some(609043));
+        assert_eq!(
+            find_suffix_for_md5_prefix("pqrstuv", "00000"),
+            Some(1048970)
+        );
+    }
 }
@@ -1,23 +1,12 @@
-use futures::{StreamExt, TryStreamExt, future};
-use tokio::{
-    fs::File,
-    io::{AsyncBufReadExt, BufReader},
-};
-use tokio_stream::wrappers::LinesStream;
+use crate::{answer::Answer, solution::Solution};
 
 const VOWELS: &str = "aeiou";
 
 const DISALLOWED_SEQUENCES: &[&str] = &["ab", "cd", "pq", "xy"];
 
-async fn answer(is_nice: impl Fn(&str) -> bool) -> anyhow::Result<()> {
-    let input = File::open("inputs/y15_d05.txt").await?;
-    let input = BufReader::new(input);
-    let nice_count = LinesStream::new(input.lines())
-        .try_filter(|line| future::ready(is_nice(line)))
-        .count()
-        .await;
-    println!("Answer: {}", nice_count);
-    Ok(())
+fn answer(lines: &[&str], is_nice: impl Fn(&str) -> bool) -> anyhow::Result<Answer> {
+    let nice_count = lines.iter().filter(|line| is_nice(line)).count();
+    Ok(nice_count.into())
 }
 
 fn is_p1_nice(line: &str) -> bool {
@@ -29,19 +18,7 @@ fn is_p1_nice(line: &str) -> bool {
         return false;
     }
 
-    let mut chars = line.chars();
-    let prev_char = chars.next();
-    for curr in chars {
-        if prev_char == Some(curr) {
-            return true;
-        }
-    }
-
-    false
-}
-
-pub async fn p1() -> anyhow::Result<()> {
-    answer(is_p1_nice).await
+    line.as_bytes().windows(2).any(|pair| pair[0] == pair[1])
 }
 
 fn is_p2_nice(line: &str) -> bool {
@@ -69,8 +46,27 @@ fn is_p2_nice(line: &str) -> bool {
         .any(|(i, a)| pairs.iter().skip(i + 2).any(|b| a == b))
 }
 
-pub async fn p2() -> anyhow::Result<()> {
-    answer(is_p2_nice).await
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<&'a str>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<Vec<&'a str>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, input: &Vec<&str>) -> anyhow::Result<Answer> {
+        answer(input, is_p1_nice)
+    }
+
+    fn part2(&self, input: &Vec<&str>) -> anyhow::Result<Answer> {
+        answer(input, is_p2_nice)
+    }
 }
 
 #[cfg(test)]
@@ -1,19 +1,16 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
-    str::FromStr,
-};
+use std::{marker::PhantomData, str::FromStr};
 
-use anyhow::{Error, anyhow};
-use futures::{TryStreamExt, future};
-use tokio::{
-    fs::File,
-    io::{AsyncBufReadExt, BufReader},
-};
-use tokio_stream::wrappers::LinesStream;
+use anyhow::{Context, Error, anyhow};
 use winnow::Parser;
 
-use super::{PointRangeInclusive, Vec2};
+use crate::{
+    answer::Answer,
+    common::{aabb::Aabb, geometry::Vec2, grid},
+    input::InputArgs,
+    output::Format,
+    run::print_part,
+    solution::{Part, Solution},
+};
 
 #[derive(Debug, Clone)]
 enum Action {
@@ -23,9 +20,9 @@ enum Action {
 }
 
 #[derive(Debug)]
-struct Instruction {
+pub struct Instruction {
     action: Action,
-    range: PointRangeInclusive<usize>,
+    rect: Aabb<usize>,
 }
 
 impl FromStr for Instruction {
@@ -36,91 +33,260 @@ impl FromStr for Instruction {
     }
 }
 
-trait Grid {
+/// How lights react to the actions, which differs between the parts.
+trait Rules {
+    type Light: Copy + Default;
+
+    fn apply(action: &Action, light: Self::Light) -> Self::Light;
+
+    /// Contribution of a single light to the answer.
+    fn value(light: Self::Light) -> u64;
+}
+
+/// Lights which are either on or off, counting the ones that are on.
+struct OnOff;
+
+impl Rules for OnOff {
+    type Light = bool;
+
+    fn apply(action: &Action, lit: bool) -> bool {
+        match action {
+            Action::TurnOn => true,
+            Action::TurnOff => false,
+            Action::Toggle => !lit,
+        }
+    }
+
+    fn value(lit: bool) -> u64 {
+        lit.into()
+    }
+}
+
+/// Lights with adjustable brightness, summing the brightness of all of them.
+struct Brightness;
+
+impl Rules for Brightness {
+    type Light = u32;
+
+    fn apply(action: &Action, brightness: u32) -> u32 {
+        match action {
+            Action::TurnOn => brightness + 1,
+            Action::TurnOff => brightness.saturating_sub(1),
+            Action::Toggle => brightness + 2,
+        }
+    }
+
+    fn value(brightness: u32) -> u64 {
+        brightness.into()
+    }
+}
+
+trait Grid: Sized {
+    /// Creates a grid of lights, all of them off, covering every instruction.
+    fn new(instructions: &[Instruction]) -> anyhow::Result<Self>;
+
     fn exec(&mut self, instruction: &Instruction);
-    fn result(&self) -> impl Display;
-}
-
-async fn answer<G: Grid + Default>() -> anyhow::Result<()> {
-    let input = File::open("inputs/y15_d06.txt").await?;
-    let input = BufReader::new(input);
-    let grid = LinesStream::new(input.lines())
-        .map_err(anyhow::Error::from)
-        .and_then(|line| future::ready(line.parse()))
-        .try_fold(G::default(), |mut grid, instruction: Instruction| {
-            grid.exec(&instruction);
-            future::ok(grid)
-        })
-        .await?;
-    println!("Answer: {}", grid.result());
-    Ok(())
+
+    fn result(&self) -> anyhow::Result<u64>;
+}
+
+fn answer<G: Grid>(instructions: &[Instruction]) -> anyhow::Result<Answer> {
+    let mut grid = G::new(instructions)?;
+    for instruction in instructions {
+        grid.exec(instruction);
+    }
+    Ok(grid.result()?.into())
 }
 
-#[derive(Default)]
-struct OnOffGrid {
-    lit: HashSet<Vec2<usize>>,
+/// Stores every light of the bounding rectangle of the instructions and
+/// updates them one by one.
+struct FlatGrid<R: Rules> {
+    origin: Vec2<usize>,
+    lights: grid::Grid<R::Light>,
 }
 
-impl Grid for OnOffGrid {
+impl<R: Rules> Grid for FlatGrid<R> {
+    fn new(instructions: &[Instruction]) -> anyhow::Result<Self> {
+        let bounds = instructions
+            .iter()
+            .map(|instruction| instruction.rect)
+            .reduce(|a, b| a.union_bounds(&b));
+        let (origin, (width, height)) = match bounds {
+            Some(bounds) => {
+                let [width, height] = (bounds.max() - bounds.min())
+                    .0
+                    .map(|len| len.checked_add(1));
+                let size = Option::zip(width, height)
+                    .filter(|&(width, height)| width.checked_mul(height).is_some())
+                    .context("Instructions cover too many lights for a flat grid")?;
+                (bounds.min(), size)
+            }
+            None => (Vec2::default(), (0, 0)),
+        };
+        Ok(Self {
+            origin,
+            lights: grid::Grid::filled(width, height, R::Light::default()),
+        })
+    }
+
     fn exec(&mut self, instruction: &Instruction) {
-        for x in instruction.range {
-            match instruction.action {
-                Action::TurnOn => {
-                    self.lit.insert(x);
-                }
-                Action::TurnOff => {
-                    self.lit.remove(&x);
-                }
-                Action::Toggle => {
-                    if self.lit.contains(&x) {
-                        self.lit.remove(&x);
-                    } else {
-                        self.lit.insert(x);
-                    }
-                }
+        for point in instruction.rect.points() {
+            if let Some(light) = self.lights.get_mut(point - self.origin) {
+                *light = R::apply(&instruction.action, *light);
             }
         }
     }
 
-    fn result(&self) -> impl Display {
-        self.lit.len()
+    fn result(&self) -> anyhow::Result<u64> {
+        Ok(self.lights.cells().map(|&light| R::value(light)).sum())
     }
 }
 
-pub async fn p1() -> anyhow::Result<()> {
-    answer::<OnOffGrid>().await
+/// Splits the plane along the edges of all the rectangles in the
+/// instructions, so every region is lit uniformly and can be updated at once
+/// regardless of its size.
+struct RegionGrid<R: Rules> {
+    /// Sorted coordinates where the regions start along each axis, ending
+    /// with the coordinate just past the last region.
+    bounds: [Vec<usize>; 2],
+    regions: grid::Grid<R::Light>,
+    rules: PhantomData<R>,
 }
 
-#[derive(Default)]
-struct BrightnessGrid {
-    brightness: HashMap<Vec2<usize>, i32>,
+impl<R: Rules> RegionGrid<R> {
+    /// Returns the rectangle of regions covering exactly the `rect`.
+    fn regions(&self, rect: Aabb<usize>) -> Aabb<usize> {
+        let index = |axis: usize, bound: usize| {
+            self.bounds[axis]
+                .binary_search(&bound)
+                .expect("Edges of every rectangle are bounds")
+        };
+        let min = Vec2::from_fn(|axis| index(axis, rect.min()[axis]));
+        // The last region is the one starting at or before the end of the
+        // rectangle, found without computing the coordinate just past it.
+        let max = Vec2::from_fn(|axis| {
+            self.bounds[axis].partition_point(|&bound| bound <= rect.max()[axis]) - 1
+        });
+        Aabb::new(min, max)
+    }
+
+    /// Returns the number of lights in the region at the given coordinates,
+    /// or `None` if it doesn't fit in a `u64`.
+    fn area(&self, region: Vec2<usize>) -> Option<u64> {
+        (0..2)
+            .map(|axis| {
+                let bounds = &self.bounds[axis];
+                (bounds[region[axis] + 1] - bounds[region[axis]]) as u64
+            })
+            .try_fold(1, u64::checked_mul)
+    }
 }
 
-impl Grid for BrightnessGrid {
+impl<R: Rules> Grid for RegionGrid<R> {
+    fn new(instructions: &[Instruction]) -> anyhow::Result<Self> {
+        let mut bounds: [Vec<usize>; 2] = Default::default();
+        for instruction in instructions {
+            let rect = instruction.rect;
+            for (axis, bounds) in bounds.iter_mut().enumerate() {
+                let end = rect.max()[axis].checked_add(1).with_context(|| {
+                    format!("Lights must have coordinates below {}", usize::MAX)
+                })?;
+                bounds.extend([rect.min()[axis], end]);
+            }
+        }
+        for bounds in &mut bounds {
+            bounds.sort_unstable();
+            bounds.dedup();
+        }
+        let [width, height] = bounds
+            .each_ref()
+            .map(|bounds| bounds.len().saturating_sub(1));
+        Ok(Self {
+            bounds,
+            regions: grid::Grid::filled(width, height, R::Light::default()),
+            rules: PhantomData,
+        })
+    }
+
     fn exec(&mut self, instruction: &Instruction) {
-        for x in instruction.range {
-            let entry = self.brightness.entry(x).or_insert(0);
-            match instruction.action {
-                Action::TurnOn => {
-                    *entry += 1;
-                }
-                Action::TurnOff => {
-                    *entry = (*entry - 1).max(0);
-                }
-                Action::Toggle => {
-                    *entry += 2;
-                }
+        for region in self.regions(instruction.rect).points() {
+            if let Some(light) = self.regions.get_mut(region) {
+                *light = R::apply(&instruction.action, *light);
             }
         }
     }
 
-    fn result(&self) -> impl Display {
-        self.brightness.values().copied().sum::<i32>()
+    fn result(&self) -> anyhow::Result<u64> {
+        self.regions
+            .iter()
+            .try_fold(0, |sum: u64, (region, &light)| {
+                sum.checked_add(R::value(light).checked_mul(self.area(region)?)?)
+            })
+            .context("Answer doesn't fit in 64 bits")
+    }
+}
+
+/// Implementation of the grid of lights used to follow the instructions.
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Layout {
+    /// Updates whole regions of uniformly lit lights at once.
+    #[default]
+    Regions,
+    /// Updates every light separately.
+    Flat,
+}
+
+#[derive(Default, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Solver {
+    layout: Layout,
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Instruction>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+
+    fn parse(&self, input: &str) -> anyhow::Result<Vec<Instruction>> {
+        input.lines().map(Instruction::from_str).try_collect()
+    }
+
+    fn part1(&self, input: &Vec<Instruction>) -> anyhow::Result<Answer> {
+        match self.layout {
+            Layout::Regions => answer::<RegionGrid<OnOff>>(input),
+            Layout::Flat => answer::<FlatGrid<OnOff>>(input),
+        }
+    }
+
+    fn part2(&self, input: &Vec<Instruction>) -> anyhow::Result<Answer> {
+        match self.layout {
+            Layout::Regions => answer::<RegionGrid<Brightness>>(input),
+            Layout::Flat => answer::<FlatGrid<Brightness>>(input),
+        }
     }
 }
 
-pub async fn p2() -> anyhow::Result<()> {
-    answer::<BrightnessGrid>().await
+/// Solves a part of the puzzle with the chosen layout of the grid, e.g.
+/// `aoc y15 d06 p1 --layout flat`.
+#[derive(clap::Args)]
+pub struct Args {
+    part: Part,
+
+    /// Implementation of the grid of lights.
+    #[arg(long, value_enum, default_value_t)]
+    layout: Layout,
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        let solver = Solver {
+            layout: self.layout,
+        };
+        print_part(&solver, self.part, input, format).await
+    }
 }
 
 mod parser {
@@ -130,7 +296,7 @@ mod parser {
         combinator::{alt, separated_pair},
     };
 
-    use crate::y15::{PointRangeInclusive, Vec2, ws};
+    use crate::common::{aabb::Aabb, geometry::Vec2, parsing::ws};
 
     use super::{Action, Instruction};
 
@@ -143,21 +309,97 @@ mod parser {
         .parse_next(input)
     }
 
-    fn range(input: &mut &str) -> Result<PointRangeInclusive<usize>> {
+    fn rect(input: &mut &str) -> Result<Aabb<usize>> {
         fn coord(input: &mut &str) -> Result<Vec2<usize>> {
-            separated_pair(digit1, ",", digit1)
-                .map(|(a, b): (&str, &str)| Vec2::new(a.parse().unwrap(), b.parse().unwrap()))
+            let number = || digit1.try_map(str::parse::<usize>);
+            separated_pair(number(), ",", number())
+                .map(|(x, y)| Vec2::new(x, y))
                 .parse_next(input)
         }
 
         separated_pair(coord, ws("through"), coord)
-            .map(|(begin, end)| begin.points_to_inclusive(end))
+            .map(|(begin, end)| Aabb::new(begin, end))
             .parse_next(input)
     }
 
     pub fn instruction(input: &mut &str) -> Result<Instruction> {
-        separated_pair(action, multispace1, range)
-            .map(|(action, range)| Instruction { action, range })
+        separated_pair(action, multispace1, rect)
+            .map(|(action, rect)| Instruction { action, rect })
             .parse_next(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Brightness, FlatGrid, Instruction, OnOff, RegionGrid, answer};
+    use crate::answer::Answer;
+
+    /// Overlapping instructions followed by one far away from them.
+    const INSTRUCTIONS: &str = "\
+turn on 0,0 through 3,3
+toggle 2,2 through 5,4
+turn off 3,0 through 3,9
+turn on 1000000,1000000 through 1000001,1000000";
+
+    #[test]
+    fn test_layouts_agree() {
+        let instructions: Vec<Instruction> =
+            INSTRUCTIONS.lines().map(str::parse).try_collect().unwrap();
+        let (near, far) = instructions.split_at(3);
+        let all = &instructions;
+
+        assert_eq!(answer::<RegionGrid<OnOff>>(near).unwrap(), Answer::UInt(17));
+        assert_eq!(answer::<FlatGrid<OnOff>>(near).unwrap(), Answer::UInt(17));
+        assert_eq!(answer::<RegionGrid<OnOff>>(all).unwrap(), Answer::UInt(19));
+        assert_eq!(answer::<FlatGrid<OnOff>>(far).unwrap(), Answer::UInt(2));
+
+        assert_eq!(
+            answer::<RegionGrid<Brightness>>(near).unwrap(),
+            Answer::UInt(35)
+        );
+        assert_eq!(
+            answer::<FlatGrid<Brightness>>(near).unwrap(),
+            Answer::UInt(35)
+        );
+        assert_eq!(
+            answer::<RegionGrid<Brightness>>(all).unwrap(),
+            Answer::UInt(37)
+        );
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let instruction = |s: String| -> Instruction { s.parse().unwrap() };
+        let max = usize::MAX;
+
+        let too_long = "turn on 0,0 through 99999999999999999999,1";
+        assert!(too_long.parse::<Instruction>().is_err());
+
+        let last = instruction(format!("toggle {},0 through {max},0", max - 1));
+        assert!(answer::<RegionGrid<OnOff>>(&[last]).is_err());
+
+        let row = instruction(format!("turn on 0,0 through {},0", max - 1));
+        assert_eq!(
+            answer::<RegionGrid<OnOff>>(&[row]).unwrap(),
+            Answer::UInt(max as u64)
+        );
+
+        let square = format!("turn on 0,0 through {},{}", max - 1, max - 1);
+        assert!(answer::<RegionGrid<OnOff>>(&[instruction(square.clone())]).is_err());
+        assert!(answer::<FlatGrid<OnOff>>(&[instruction(square)]).is_err());
+    }
+
+    #[test]
+    fn test_no_instructions() {
+        assert_eq!(answer::<RegionGrid<OnOff>>(&[]).unwrap(), Answer::UInt(0));
+        assert_eq!(answer::<FlatGrid<OnOff>>(&[]).unwrap(), Answer::UInt(0));
+        assert_eq!(
+            answer::<RegionGrid<Brightness>>(&[]).unwrap(),
+            Answer::UInt(0)
+        );
+        assert_eq!(
+            answer::<FlatGrid<Brightness>>(&[]).unwrap(),
+            Answer::UInt(0)
+        );
+    }
+}
@@ -0,0 +1,356 @@
+//! Evaluation of circuits of wires connected by bitwise logic gates.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use super::{ConnectionSource, Identifier, Operation, State, Value, mask};
+
+/// A problem with the connections of a circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The `wire` has no connection, but the wires in `chain` depend on it,
+    /// each on the next one, starting with the requested wire and ending with
+    /// the `wire` itself.
+    UndefinedWire { wire: String, chain: Vec<String> },
+    /// The wires form a loop, each reading the next one and the last one
+    /// being the first one again.
+    Loop(Vec<String>),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedWire { wire, chain } if chain.len() > 1 => write!(
+                f,
+                "Wire {wire} has no connection, needed through {}",
+                chain.join(" -> ")
+            ),
+            Self::UndefinedWire { wire, .. } => write!(f, "Wire {wire} has no connection"),
+            Self::Loop(wires) => write!(f, "Wires form a loop: {}", wires.join(" -> ")),
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+/// Signals of all the wires of a circuit, carrying values `width` bits wide.
+///
+/// Wires depending on a wire without a connection have no signal.
+#[derive(Debug, Clone)]
+pub struct Circuit<'a> {
+    mask: u64,
+    sources: HashMap<Identifier<'a>, ConnectionSource<'a>>,
+    /// Wires reading each wire.
+    readers: HashMap<Identifier<'a>, Vec<Identifier<'a>>>,
+    signals: HashMap<Identifier<'a>, u64>,
+}
+
+impl<'a> Circuit<'a> {
+    /// Evaluates all the wires of the circuit connected in the `state`, with
+    /// every signal masked to `width` bits.
+    ///
+    /// Fails with [`CircuitError::Loop`] if any wires form a loop.
+    pub fn new(state: &State<'a>, width: u32) -> Result<Self> {
+        let mut circuit = Self {
+            mask: mask(width)?,
+            sources: state.signals.clone(),
+            readers: HashMap::new(),
+            signals: HashMap::new(),
+        };
+        if let Some(wires) = circuit.find_loop() {
+            let wires = wires.into_iter().map(str::to_owned).collect();
+            return Err(CircuitError::Loop(wires).into());
+        }
+        for (&wire, source) in &circuit.sources {
+            for input in source.inputs() {
+                circuit.readers.entry(input).or_default().push(wire);
+            }
+        }
+        circuit.evaluate(circuit.sources.keys().copied().collect());
+        Ok(circuit)
+    }
+
+    /// Overrides the signal on the `wire` with the `value`, as if it was
+    /// connected to a literal, and evaluates again just the wires depending on
+    /// it. Returns the number of evaluated wires, including the `wire` itself.
+    ///
+    /// Wires without a connection can be overridden too.
+    pub fn set(&mut self, wire: Identifier<'a>, value: u64) -> usize {
+        let literal = ConnectionSource::Literal(value);
+        if let Some(source) = self.sources.insert(wire, literal) {
+            for input in source.inputs() {
+                if let Some(readers) = self.readers.get_mut(input) {
+                    readers.retain(|&reader| reader != wire);
+                }
+            }
+        }
+        let downstream = self.downstream(wire);
+        let evaluated = downstream.len();
+        self.evaluate(downstream);
+        evaluated
+    }
+
+    /// Returns the signal on the `wire`, or the undefined wire it depends on.
+    pub fn signal(&self, wire: &str) -> Result<u64, CircuitError> {
+        self.get(wire).ok_or_else(|| self.undefined(wire))
+    }
+
+    /// Returns the signals on all the `wires`, failing on the first one
+    /// depending on an undefined wire.
+    pub fn query<'w>(
+        &self,
+        wires: impl IntoIterator<Item = &'w str>,
+    ) -> Result<Vec<u64>, CircuitError> {
+        wires.into_iter().map(|wire| self.signal(wire)).collect()
+    }
+
+    fn get(&self, wire: &str) -> Option<u64> {
+        self.signals.get(wire).copied()
+    }
+
+    /// Follows the inputs without a signal from the `wire` to a wire without
+    /// a connection.
+    ///
+    /// Wires without a signal always lead to one, as long as there are no
+    /// loops.
+    fn undefined(&self, wire: &str) -> CircuitError {
+        let mut chain = vec![wire.to_owned()];
+        let mut wire = wire;
+        while let Some(source) = self.sources.get(wire) {
+            wire = source
+                .inputs()
+                .into_iter()
+                .find(|input| self.get(input).is_none())
+                .expect("Wires without a signal have an input without one");
+            chain.push(wire.to_owned());
+        }
+        CircuitError::UndefinedWire {
+            wire: wire.to_owned(),
+            chain,
+        }
+    }
+
+    /// Returns the `wire` together with all the wires reading it, directly
+    /// or through other wires.
+    fn downstream(&self, wire: Identifier<'a>) -> HashSet<Identifier<'a>> {
+        let mut wires = HashSet::from([wire]);
+        let mut queue = vec![wire];
+        while let Some(wire) = queue.pop() {
+            for &reader in self.readers.get(wire).into_iter().flatten() {
+                if wires.insert(reader) {
+                    queue.push(reader);
+                }
+            }
+        }
+        wires
+    }
+
+    /// Evaluates the connected `wires` using Kahn's algorithm, so that each
+    /// of them is evaluated after all of its inputs among the `wires`.
+    fn evaluate(&mut self, wires: HashSet<Identifier<'a>>) {
+        let mut missing: HashMap<_, _> = wires
+            .iter()
+            .map(|&wire| {
+                let inputs = self.sources[wire].inputs();
+                let count = inputs.iter().filter(|&input| wires.contains(input)).count();
+                (wire, count)
+            })
+            .collect();
+        let mut ready: VecDeque<_> = missing
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&wire, _)| wire)
+            .collect();
+        while let Some(wire) = ready.pop_front() {
+            self.eval(wire);
+            for &reader in self.readers.get(wire).into_iter().flatten() {
+                if let Some(count) = missing.get_mut(reader) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push_back(reader);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Searches for a loop with depth-first search, returning its wires with
+    /// the first one repeated at the end.
+    fn find_loop(&self) -> Option<Vec<Identifier<'a>>> {
+        enum Visit {
+            InProgress,
+            Done,
+        }
+
+        let defined_inputs = |wire: Identifier<'a>| -> Vec<_> {
+            self.sources[wire]
+                .inputs()
+                .into_iter()
+                .filter(|input| self.sources.contains_key(input))
+                .collect()
+        };
+        let mut visits = HashMap::new();
+        for &start in self.sources.keys().sorted_unstable() {
+            if visits.contains_key(start) {
+                continue;
+            }
+            visits.insert(start, Visit::InProgress);
+            let mut stack = vec![(start, defined_inputs(start).into_iter())];
+            while let Some((wire, inputs)) = stack.last_mut() {
+                let Some(input) = inputs.next() else {
+                    visits.insert(*wire, Visit::Done);
+                    stack.pop();
+                    continue;
+                };
+                match visits.get(input) {
+                    Some(Visit::InProgress) => {
+                        let first = stack
+                            .iter()
+                            .position(|&(wire, _)| wire == input)
+                            .expect("Wires in progress are on the stack");
+                        let mut wires: Vec<_> =
+                            stack[first..].iter().map(|&(wire, _)| wire).collect();
+                        wires.push(input);
+                        return Some(wires);
+                    }
+                    Some(Visit::Done) => {}
+                    None => {
+                        visits.insert(input, Visit::InProgress);
+                        stack.push((input, defined_inputs(input).into_iter()));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Computes the signal on the `wire` from the already evaluated signals
+    /// on its inputs.
+    fn eval(&mut self, wire: Identifier<'a>) {
+        let signal = match &self.sources[wire] {
+            ConnectionSource::Literal(value) => Some(*value),
+            ConnectionSource::Identifier(input) => self.get(input),
+            ConnectionSource::Operation(op) => self.eval_op(op),
+        };
+        match signal {
+            Some(signal) => self.signals.insert(wire, signal & self.mask),
+            None => self.signals.remove(wire),
+        };
+    }
+
+    fn eval_op(&self, op: &Operation<'a>) -> Option<u64> {
+        let signal = op.apply(|value| self.eval_value(value))?;
+        Some(signal & self.mask)
+    }
+
+    fn eval_value(&self, value: &Value<'a>) -> Option<u64> {
+        match value {
+            Value::Literal(literal) => Some(literal & self.mask),
+            Value::Identifier(wire) => self.get(wire),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Circuit, CircuitError};
+    use crate::y15::d07::state_from_str;
+
+    /// Example circuit from the puzzle description.
+    const EXAMPLE: &str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    #[test]
+    fn test_example() {
+        let state = state_from_str(EXAMPLE).unwrap();
+        let circuit = Circuit::new(&state, 16).unwrap();
+        assert_eq!(
+            circuit
+                .query(["d", "e", "f", "g", "h", "i", "x", "y"])
+                .unwrap(),
+            [72, 507, 492, 114, 65412, 65079, 123, 456]
+        );
+        assert!(circuit.query(["d", "a"]).is_err());
+    }
+
+    #[test]
+    fn test_width() {
+        let state = state_from_str(EXAMPLE).unwrap();
+        let circuit = Circuit::new(&state, 8).unwrap();
+        assert_eq!(circuit.query(["y", "f", "h"]).unwrap(), [200, 236, 132]);
+        assert_eq!(Circuit::new(&state, 64).unwrap().signal("h"), Ok(!123));
+        assert!(Circuit::new(&state, 0).is_err());
+        assert!(Circuit::new(&state, 65).is_err());
+    }
+
+    #[test]
+    fn test_set() {
+        let state = state_from_str("b OR c -> a\nc LSHIFT 1 -> b\n3 -> c\nc -> d\nNOT e -> f");
+        let mut circuit = Circuit::new(&state.unwrap(), 16).unwrap();
+        assert_eq!(circuit.query(["a", "b", "d"]), Ok(vec![7, 6, 3]));
+
+        assert_eq!(circuit.set("b", 8), 2);
+        assert_eq!(circuit.query(["a", "b", "d"]), Ok(vec![11, 8, 3]));
+        // Wire b doesn't read wire c anymore.
+        assert_eq!(circuit.set("c", 1), 3);
+        assert_eq!(circuit.query(["a", "b", "d"]), Ok(vec![9, 8, 1]));
+
+        assert!(circuit.signal("f").is_err());
+        assert_eq!(circuit.set("e", 0x00ff), 2);
+        assert_eq!(circuit.signal("f"), Ok(0xff00));
+        assert_eq!(circuit.set("x", 5), 1);
+    }
+
+    #[test]
+    fn test_undefined_wire() {
+        let state = state_from_str("c -> a\nz AND 1 -> c\n1 -> d\nd OR y -> e").unwrap();
+        let circuit = Circuit::new(&state, 16).unwrap();
+        let err = circuit.query(["d", "a", "e"]).unwrap_err();
+        assert_eq!(
+            err,
+            CircuitError::UndefinedWire {
+                wire: "z".to_owned(),
+                chain: vec!["a".to_owned(), "c".to_owned(), "z".to_owned()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Wire z has no connection, needed through a -> c -> z"
+        );
+        assert_eq!(
+            circuit.signal("q").unwrap_err().to_string(),
+            "Wire q has no connection"
+        );
+        assert_eq!(circuit.signal("d"), Ok(1));
+    }
+
+    #[test]
+    fn test_loop() {
+        let state = state_from_str("1 -> a\na AND d -> b\nb -> c\nNOT c -> d\nd -> e").unwrap();
+        let err = Circuit::new(&state, 16).unwrap_err();
+        assert_eq!(
+            err.downcast_ref(),
+            Some(&CircuitError::Loop(
+                ["b", "d", "c", "b"].map(str::to_owned).to_vec()
+            ))
+        );
+        assert_eq!(err.to_string(), "Wires form a loop: b -> d -> c -> b");
+
+        let state = state_from_str("x -> x").unwrap();
+        let err = Circuit::new(&state, 16).unwrap_err();
+        assert_eq!(err.to_string(), "Wires form a loop: x -> x");
+    }
+}
@@ -0,0 +1,96 @@
+//! Drawing the circuit as a Graphviz graph.
+
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+
+use super::{ConnectionSource, State, Value, circuit::Circuit};
+use crate::common::dot::Graph;
+
+/// Draws every wire of the circuit as a node, labelled with its signal when
+/// the `circuit` could be evaluated, and every gate as a box between the
+/// wires it reads and the wire it drives. Wires that are read but have no
+/// connection are dashed.
+pub fn graph<'a>(state: &State<'a>, circuit: Option<&Circuit>) -> Graph {
+    let mut graph = Graph::directed();
+    let mut undefined = BTreeSet::new();
+    // Wires are lowercase letters, so node ids with a colon can't clash with
+    // them.
+    let mut read = |graph: &mut Graph, value: &Value<'a>, reader: &str, node: String| match value {
+        Value::Literal(literal) => {
+            graph
+                .node(
+                    &node,
+                    &[("label", &literal.to_string()), ("shape", "plaintext")],
+                )
+                .edge(&node, reader, &[]);
+        }
+        Value::Identifier(wire) => {
+            if !state.signals.contains_key(wire) {
+                undefined.insert(*wire);
+            }
+            graph.edge(wire, reader, &[]);
+        }
+    };
+
+    for (&wire, source) in state.signals.iter().sorted_by_key(|(wire, _)| **wire) {
+        let label = match circuit.and_then(|circuit| circuit.signal(wire).ok()) {
+            Some(signal) => format!("{wire}\n{signal}"),
+            None => wire.to_owned(),
+        };
+        graph.node(wire, &[("label", &label)]);
+
+        match source {
+            ConnectionSource::Literal(literal) => read(
+                &mut graph,
+                &Value::Literal(*literal),
+                wire,
+                format!("{wire}:"),
+            ),
+            ConnectionSource::Identifier(input) => read(
+                &mut graph,
+                &Value::Identifier(input),
+                wire,
+                format!("{wire}:"),
+            ),
+            ConnectionSource::Operation(op) => {
+                let gate = format!("{wire}:gate");
+                graph.node(&gate, &[("label", &op.gate()), ("shape", "box")]);
+                for (i, value) in op.operands().into_iter().enumerate() {
+                    read(&mut graph, value, &gate, format!("{wire}:{i}"));
+                }
+                graph.edge(&gate, wire, &[]);
+            }
+        }
+    }
+
+    for wire in undefined {
+        graph.node(wire, &[("style", "dashed")]);
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::graph;
+    use crate::y15::d07::{circuit::Circuit, state_from_str};
+
+    #[test]
+    fn test_graph() {
+        let state = state_from_str("123 -> x\nx AND y -> d\nx LSHIFT 2 -> f\nd -> e").unwrap();
+        let dot = graph(&state, None).to_string();
+        assert!(dot.contains("\"d:gate\" [label=\"AND\", shape=\"box\"]"));
+        assert!(dot.contains("\"x\" -> \"d:gate\""));
+        assert!(dot.contains("\"d:gate\" -> \"d\""));
+        assert!(dot.contains("\"f:gate\" [label=\"LSHIFT 2\", shape=\"box\"]"));
+        assert!(dot.contains("\"x:\" [label=\"123\", shape=\"plaintext\"]"));
+        assert!(dot.contains("\"d\" -> \"e\""));
+        assert!(dot.contains("\"y\" [style=\"dashed\"]"));
+
+        let state = state_from_str("123 -> x\n456 -> y\nx AND y -> d").unwrap();
+        let circuit = Circuit::new(&state, 16).unwrap();
+        let dot = graph(&state, Some(&circuit)).to_string();
+        assert!(dot.contains("\"d\" [label=\"d\\n72\"]"));
+        assert!(!dot.contains("dashed"));
+    }
+}
@@ -0,0 +1,561 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{Context, Error, anyhow, ensure};
+use itertools::Itertools;
+use serde::Serialize;
+use winnow::Parser;
+
+use crate::{
+    answer::Answer,
+    common::dot::Graph,
+    input::InputArgs,
+    output::{Format, print_json},
+    run::print_part,
+    solution::{Part, Solution},
+};
+
+use self::circuit::{Circuit, CircuitError};
+
+mod circuit;
+mod graph;
+mod optimize;
+
+/// Width of the signals carried by the wires, in bits.
+const WIDTH: u32 = 16;
+
+/// Returns the mask keeping the lowest `width` bits of a signal.
+fn mask(width: u32) -> anyhow::Result<u64> {
+    ensure!(
+        (1..=u64::BITS).contains(&width),
+        "Width of wires must be between 1 and {} bits, got {width}",
+        u64::BITS
+    );
+    Ok(u64::MAX >> (u64::BITS - width))
+}
+
+mod parser {
+    use winnow::{
+        ModalResult, Parser,
+        ascii::dec_uint,
+        combinator::{alt, cut_err, fail, preceded, separated_pair},
+        error::{StrContext, StrContextValue},
+        token::take_while,
+    };
+
+    use crate::common::parsing::ws;
+
+    use super::{Connection, ConnectionSource, Identifier, Operation, Value};
+
+    pub fn identifier<'a>(input: &mut &'a str) -> ModalResult<Identifier<'a>> {
+        take_while(1.., |ch: char| ch.is_ascii_lowercase()).parse_next(input)
+    }
+
+    fn value<'a>(input: &mut &'a str) -> ModalResult<Value<'a>> {
+        alt((
+            identifier.map(Value::Identifier),
+            dec_uint.map(Value::Literal),
+            fail.context(StrContext::Label("value"))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "identifier",
+                )))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "unsigned integer",
+                ))),
+        ))
+        .parse_next(input)
+    }
+
+    fn operation<'a>(input: &mut &'a str) -> ModalResult<Operation<'a>> {
+        alt((
+            preceded(ws("NOT"), cut_err(value)).map(Operation::Not),
+            separated_pair(value, ws("AND"), value).map(|(lhs, rhs)| Operation::And(lhs, rhs)),
+            separated_pair(value, ws("OR"), value).map(|(lhs, rhs)| Operation::Or(lhs, rhs)),
+            separated_pair(value, ws("LSHIFT"), dec_uint)
+                .map(|(lhs, rhs)| Operation::LShift(lhs, rhs)),
+            separated_pair(value, ws("RSHIFT"), dec_uint)
+                .map(|(lhs, rhs)| Operation::RShift(lhs, rhs)),
+            fail.context(StrContext::Label("operation"))
+                .context(StrContext::Expected(StrContextValue::StringLiteral("not")))
+                .context(StrContext::Expected(StrContextValue::StringLiteral("or")))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "lshift",
+                )))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "rshift",
+                ))),
+        ))
+        .parse_next(input)
+    }
+
+    fn connection_source<'a>(input: &mut &'a str) -> ModalResult<ConnectionSource<'a>> {
+        alt((
+            operation.map(ConnectionSource::Operation),
+            identifier.map(ConnectionSource::Identifier),
+            dec_uint.map(ConnectionSource::Literal),
+            fail.context(StrContext::Label("connection source"))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "operation",
+                )))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "identifier",
+                )))
+                .context(StrContext::Expected(StrContextValue::StringLiteral(
+                    "unsigned integer",
+                ))),
+        ))
+        .parse_next(input)
+    }
+
+    pub fn connection<'a>(input: &mut &'a str) -> ModalResult<Connection<'a>> {
+        separated_pair(connection_source, ws("->"), identifier)
+            .map(|(from, to)| Connection { from, to })
+            .context(StrContext::Label("connection"))
+            .parse_next(input)
+    }
+}
+
+type Identifier<'a> = &'a str;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value<'a> {
+    Literal(u64),
+    Identifier(Identifier<'a>),
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(lit) => write!(f, "{}", lit),
+            Self::Identifier(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operation<'a> {
+    Not(Value<'a>),
+    And(Value<'a>, Value<'a>),
+    Or(Value<'a>, Value<'a>),
+    LShift(Value<'a>, u32),
+    RShift(Value<'a>, u32),
+}
+
+impl Display for Operation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Not(value) => write!(f, "NOT {}", value),
+            Self::And(lhs, rhs) => write!(f, "{} AND {}", lhs, rhs),
+            Self::Or(lhs, rhs) => write!(f, "{} OR {}", lhs, rhs),
+            Self::LShift(value, shift) => write!(f, "{} LSHIFT {}", value, shift),
+            Self::RShift(value, shift) => write!(f, "{} RSHIFT {}", value, shift),
+        }
+    }
+}
+
+impl<'a> Operation<'a> {
+    /// Values the gate reads, in the order they are written.
+    fn operands(&self) -> Vec<&Value<'a>> {
+        match self {
+            Self::Not(value) | Self::LShift(value, _) | Self::RShift(value, _) => vec![value],
+            Self::And(lhs, rhs) | Self::Or(lhs, rhs) => vec![lhs, rhs],
+        }
+    }
+
+    /// Same operation reading the values returned by `f` for its operands.
+    fn map_operands(&self, mut f: impl FnMut(&Value<'a>) -> Value<'a>) -> Self {
+        match self {
+            Self::Not(value) => Self::Not(f(value)),
+            Self::And(lhs, rhs) => Self::And(f(lhs), f(rhs)),
+            Self::Or(lhs, rhs) => Self::Or(f(lhs), f(rhs)),
+            Self::LShift(value, shift) => Self::LShift(f(value), *shift),
+            Self::RShift(value, shift) => Self::RShift(f(value), *shift),
+        }
+    }
+
+    /// Computes the output of the gate from the signals returned by `signal`
+    /// for its operands, or `None` if any of them is `None`. The output isn't
+    /// masked to the width of the wires.
+    fn apply(&self, mut signal: impl FnMut(&Value<'a>) -> Option<u64>) -> Option<u64> {
+        let output = match self {
+            Self::Not(value) => !signal(value)?,
+            Self::And(lhs, rhs) => signal(lhs)? & signal(rhs)?,
+            Self::Or(lhs, rhs) => signal(lhs)? | signal(rhs)?,
+            Self::LShift(value, shift) => signal(value)?.checked_shl(*shift).unwrap_or(0),
+            Self::RShift(value, shift) => signal(value)?.checked_shr(*shift).unwrap_or(0),
+        };
+        Some(output)
+    }
+
+    /// Name of the gate without its operands, e.g. `LSHIFT 2`.
+    fn gate(&self) -> String {
+        match self {
+            Self::Not(_) => "NOT".to_owned(),
+            Self::And(..) => "AND".to_owned(),
+            Self::Or(..) => "OR".to_owned(),
+            Self::LShift(_, shift) => format!("LSHIFT {shift}"),
+            Self::RShift(_, shift) => format!("RSHIFT {shift}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConnectionSource<'a> {
+    Literal(u64),
+    Identifier(Identifier<'a>),
+    Operation(Operation<'a>),
+}
+
+impl Display for ConnectionSource<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(lit) => write!(f, "{}", lit),
+            Self::Identifier(id) => write!(f, "{}", id),
+            Self::Operation(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+impl<'a> ConnectionSource<'a> {
+    /// Returns the wires read by the source, each of them once.
+    fn inputs(&self) -> Vec<Identifier<'a>> {
+        let values = match self {
+            Self::Literal(_) => vec![],
+            Self::Identifier(wire) => return vec![wire],
+            Self::Operation(op) => op.operands(),
+        };
+        let mut wires: Vec<_> = values
+            .into_iter()
+            .filter_map(|value| match value {
+                Value::Literal(_) => None,
+                Value::Identifier(wire) => Some(*wire),
+            })
+            .collect();
+        wires.dedup();
+        wires
+    }
+}
+
+#[derive(Debug)]
+struct Connection<'a> {
+    from: ConnectionSource<'a>,
+    to: Identifier<'a>,
+}
+
+impl<'a> TryFrom<&'a str> for Connection<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        parser::connection
+            .parse(s)
+            .map_err(|err| anyhow!("\n{err}"))
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct State<'a> {
+    signals: HashMap<Identifier<'a>, ConnectionSource<'a>>,
+}
+
+impl<'a> State<'a> {
+    fn set_connection(&mut self, conn: Connection<'a>) {
+        self.signals.insert(conn.to, conn.from);
+    }
+}
+
+/// Prints the connections in the format of the puzzle input, one per line,
+/// ordered by the wires they drive.
+impl Display for State<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for wire in self.signals.keys().sorted() {
+            writeln!(f, "{} -> {}", self.signals[wire], wire)?;
+        }
+        Ok(())
+    }
+}
+
+fn state_from_str(input: &str) -> anyhow::Result<State<'_>> {
+    let state =
+        input
+            .lines()
+            .map(Connection::try_from)
+            .try_fold(State::default(), |mut state, conn| {
+                state.set_connection(conn?);
+                Ok::<_, anyhow::Error>(state)
+            })?;
+    Ok(state)
+}
+
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Solver {
+    /// Wire whose signal answers both parts.
+    wire: String,
+    /// Wire overridden with the first answer in the second part.
+    overridden: String,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            wire: "a".to_owned(),
+            overridden: "b".to_owned(),
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = State<'a>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+
+    fn parse<'a>(&self, input: &'a str) -> anyhow::Result<State<'a>> {
+        state_from_str(input)
+    }
+
+    fn part1(&self, state: &State) -> anyhow::Result<Answer> {
+        Ok(Circuit::new(state, WIDTH)?.signal(&self.wire)?.into())
+    }
+
+    fn part2(&self, state: &State) -> anyhow::Result<Answer> {
+        let mut circuit = Circuit::new(state, WIDTH)?;
+        let signal = circuit.signal(&self.wire)?;
+        circuit.set(&self.overridden, signal);
+        Ok(circuit.signal(&self.wire)?.into())
+    }
+}
+
+/// Solves a part of the puzzle, e.g. `aoc y15 d07 p1`, or explores the
+/// circuit.
+#[derive(clap::Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(required = true)]
+    part: Option<Part>,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    Eval(EvalArgs),
+    Graph(GraphArgs),
+    Optimize(OptimizeArgs),
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        match (&self.command, self.part) {
+            (Some(Command::Eval(args)), _) => args.run(input, format).await,
+            (Some(Command::Graph(args)), _) => args.run(input).await,
+            (Some(Command::Optimize(args)), _) => args.run(input).await,
+            (None, Some(part)) => print_part(&Solver::default(), part, input, format).await,
+            (None, None) => unreachable!("Part is required without a command"),
+        }
+    }
+}
+
+/// Options for building the circuit of the puzzle input.
+#[derive(clap::Args)]
+struct CircuitArgs {
+    /// Overrides the signal on a wire, e.g. `b=3176`, as if it was connected
+    /// to a literal.
+    #[arg(long = "set", value_name = "WIRE=SIGNAL", value_parser = parse_override)]
+    overrides: Vec<(String, u64)>,
+
+    /// Width of the signals, in bits.
+    #[arg(long, default_value_t = WIDTH)]
+    width: u32,
+}
+
+impl CircuitArgs {
+    /// Connects every overridden wire of the `state` to a literal carrying
+    /// its signal, so loops and undefined wires are checked with the
+    /// overrides in place.
+    fn apply<'a>(&'a self, state: &mut State<'a>) -> anyhow::Result<()> {
+        let mask = mask(self.width)?;
+        for (wire, signal) in &self.overrides {
+            ensure!(
+                signal & !mask == 0,
+                "Signal {signal} on wire {wire} doesn't fit in {} bits",
+                self.width
+            );
+            state.set_connection(Connection {
+                from: ConnectionSource::Literal(*signal),
+                to: wire,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Prints signals on the given wires, optionally overriding signals on other
+/// wires first, e.g. `aoc y15 d07 eval a --set b=3176 --set c=0`.
+#[derive(clap::Args)]
+struct EvalArgs {
+    /// Wires to print the signals of.
+    #[arg(required = true)]
+    wires: Vec<String>,
+
+    #[command(flatten)]
+    circuit: CircuitArgs,
+}
+
+impl EvalArgs {
+    async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        let solver = Solver::default();
+        let input = input.read(&solver).await?;
+        let mut state = solver.parse(&input)?;
+        self.circuit.apply(&mut state)?;
+        let circuit = Circuit::new(&state, self.circuit.width)?;
+        let signals = circuit.query(self.wires.iter().map(String::as_str))?;
+
+        let wires = self.wires.iter().zip(signals);
+        match format {
+            Format::Text => {
+                for (wire, signal) in wires {
+                    println!("{wire}: {signal}");
+                }
+            }
+            Format::Json => print_json(wires.map(|(wire, signal)| Signal { wire, signal }))?,
+        }
+        Ok(())
+    }
+}
+
+/// Prints the circuit as a Graphviz graph, e.g.
+/// `aoc y15 d07 graph | dot -Tsvg > circuit.svg`. Wires are labelled with
+/// their signals, unless the circuit has a loop or undefined wires.
+#[derive(clap::Args)]
+struct GraphArgs {
+    #[command(flatten)]
+    circuit: CircuitArgs,
+}
+
+impl GraphArgs {
+    async fn run(&self, input: &InputArgs) -> anyhow::Result<()> {
+        let solver = Solver::default();
+        let input = input.read(&solver).await?;
+        let state = solver.parse(&input)?;
+        print!("{}", self.graph(state)?);
+        Ok(())
+    }
+
+    /// Draws the circuit of the `state` with the overrides in place, so
+    /// overridden wires are connected to literals instead of their gates.
+    fn graph<'a>(&'a self, mut state: State<'a>) -> anyhow::Result<Graph> {
+        self.circuit.apply(&mut state)?;
+        let circuit = match Circuit::new(&state, self.circuit.width) {
+            Ok(circuit) => Some(circuit),
+            Err(err) if err.is::<CircuitError>() => {
+                eprintln!("Drawing the circuit without signals: {err}");
+                None
+            }
+            Err(err) => return Err(err),
+        };
+        Ok(graph::graph(&state, circuit.as_ref()))
+    }
+}
+
+/// Prints the connections needed for the signals on the given wires,
+/// simplified by folding constants and skipping wires connected straight to
+/// another wire, in the format of the puzzle input, e.g.
+/// `aoc y15 d07 optimize a --set b=3176`.
+#[derive(clap::Args)]
+struct OptimizeArgs {
+    /// Wires whose signals the simplified circuit must keep.
+    #[arg(required = true)]
+    wires: Vec<String>,
+
+    #[command(flatten)]
+    circuit: CircuitArgs,
+}
+
+impl OptimizeArgs {
+    async fn run(&self, input: &InputArgs) -> anyhow::Result<()> {
+        let solver = Solver::default();
+        let input = input.read(&solver).await?;
+        let mut state = solver.parse(&input)?;
+        self.circuit.apply(&mut state)?;
+        let outputs: Vec<_> = self.wires.iter().map(String::as_str).collect();
+        for wire in &outputs {
+            ensure!(
+                state.signals.contains_key(wire),
+                "Wire {wire} has no connection"
+            );
+        }
+        print!(
+            "{}",
+            optimize::optimize(&state, &outputs, self.circuit.width)?
+        );
+        Ok(())
+    }
+}
+
+/// Machine-readable signal on a wire.
+#[derive(Serialize)]
+struct Signal<'a> {
+    wire: &'a str,
+    signal: u64,
+}
+
+/// Parses an override of a signal in the `wire=signal` format.
+fn parse_override(s: &str) -> anyhow::Result<(String, u64)> {
+    let (wire, signal) = s
+        .split_once('=')
+        .context("Override must be in the WIRE=SIGNAL format")?;
+    let wire = parser::identifier
+        .parse(wire)
+        .map_err(|err| anyhow!("Invalid wire {wire:?}: {err}"))?;
+    Ok((wire.to_owned(), signal.parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{CircuitArgs, GraphArgs, circuit::Circuit, state_from_str};
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        circuit: CircuitArgs,
+    }
+
+    #[derive(Parser)]
+    struct GraphCli {
+        #[command(flatten)]
+        graph: GraphArgs,
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let args = Cli::parse_from(["eval", "--set", "b=1"]).circuit;
+        let mut state = state_from_str("b -> a\na AND 1 -> b").unwrap();
+        assert!(Circuit::new(&state, 16).is_err());
+        args.apply(&mut state).unwrap();
+        assert_eq!(Circuit::new(&state, 16).unwrap().signal("a").unwrap(), 1);
+
+        let args = Cli::parse_from(["eval", "--set", "b=256", "--width", "8"]).circuit;
+        let mut state = state_from_str("b -> a").unwrap();
+        let err = args.apply(&mut state).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Signal 256 on wire b doesn't fit in 8 bits"
+        );
+    }
+
+    #[test]
+    fn test_graph_overrides() {
+        let state = state_from_str("123 -> x\nNOT x -> y\ny OR x -> z").unwrap();
+        let args = GraphCli::parse_from(["graph", "--set", "y=7"]).graph;
+        let dot = args.graph(state).unwrap().to_string();
+        assert!(dot.contains("\"y:\" [label=\"7\", shape=\"plaintext\"]"));
+        assert!(dot.contains("\"y:\" -> \"y\""));
+        assert!(dot.contains("\"y\" [label=\"y\\n7\"]"));
+        assert!(!dot.contains("y:gate"));
+        assert!(dot.contains("\"z\" [label=\"z\\n127\"]"));
+    }
+}
@@ -0,0 +1,158 @@
+//! Simplifying the netlist of a circuit without evaluating all of it.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+
+use super::{ConnectionSource, Identifier, State, Value, mask};
+
+type Sources<'a> = HashMap<Identifier<'a>, ConnectionSource<'a>>;
+
+/// Simplifies the circuit of the `state` to the connections needed for the
+/// signals on the `outputs`, with signals `width` bits wide:
+///
+/// - gates reading only literals are folded into literals,
+/// - wires connected straight to another wire are replaced by that wire
+///   wherever they are read,
+/// - wires none of the `outputs` depend on are removed.
+///
+/// The outputs themselves are always kept. Wires forming a loop or depending
+/// on a wire without a connection are kept as they are, apart from the
+/// simplified operands.
+pub fn optimize<'a>(
+    state: &State<'a>,
+    outputs: &[Identifier<'a>],
+    width: u32,
+) -> Result<State<'a>> {
+    let mask = mask(width)?;
+    let mut signals = state.signals.clone();
+
+    // Folding a wire can make the wires reading it foldable too, so repeats
+    // until nothing changes.
+    let wires: Vec<_> = signals.keys().copied().collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &wire in &wires {
+            let source = simplify(&signals, &signals[wire], mask);
+            if source != signals[wire] {
+                signals.insert(wire, source);
+                changed = true;
+            }
+        }
+    }
+
+    let mut live = HashSet::new();
+    let mut stack = outputs.to_vec();
+    while let Some(wire) = stack.pop() {
+        if live.insert(wire) {
+            stack.extend(
+                signals
+                    .get(wire)
+                    .into_iter()
+                    .flat_map(ConnectionSource::inputs),
+            );
+        }
+    }
+    signals.retain(|wire, _| live.contains(wire));
+
+    Ok(State { signals })
+}
+
+/// Replaces the operands of the `source` with literals or the wires they are
+/// connected to, folding it into a literal if it reads only literals.
+fn simplify<'a>(
+    signals: &Sources<'a>,
+    source: &ConnectionSource<'a>,
+    mask: u64,
+) -> ConnectionSource<'a> {
+    match source {
+        ConnectionSource::Literal(literal) => ConnectionSource::Literal(literal & mask),
+        ConnectionSource::Identifier(wire) => match resolve(signals, wire, mask) {
+            Value::Literal(literal) => ConnectionSource::Literal(literal),
+            Value::Identifier(wire) => ConnectionSource::Identifier(wire),
+        },
+        ConnectionSource::Operation(op) => {
+            let op = op.map_operands(|value| match value {
+                Value::Literal(literal) => Value::Literal(literal & mask),
+                Value::Identifier(wire) => resolve(signals, wire, mask),
+            });
+            let literal = op.apply(|value| match value {
+                Value::Literal(literal) => Some(*literal),
+                Value::Identifier(_) => None,
+            });
+            match literal {
+                Some(literal) => ConnectionSource::Literal(literal & mask),
+                None => ConnectionSource::Operation(op),
+            }
+        }
+    }
+}
+
+/// Follows the `wire` through wires connected straight to another wire, up to
+/// a literal or a wire driven by a gate. Stops at the first repeated wire if
+/// the connections form a loop.
+fn resolve<'a>(signals: &Sources<'a>, mut wire: Identifier<'a>, mask: u64) -> Value<'a> {
+    let mut seen = HashSet::new();
+    while seen.insert(wire) {
+        match signals.get(wire) {
+            Some(ConnectionSource::Literal(literal)) => return Value::Literal(literal & mask),
+            Some(ConnectionSource::Identifier(input)) => wire = input,
+            _ => break,
+        }
+    }
+    Value::Identifier(wire)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::optimize;
+    use crate::y15::d07::{circuit::Circuit, state_from_str};
+
+    #[test]
+    fn test_fold_constants() {
+        let input = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nd LSHIFT 2 -> f\nNOT e -> g\n\
+                     f OR g -> h";
+        let state = state_from_str(input).unwrap();
+        let optimized = optimize(&state, &["h"], 16).unwrap();
+        assert_eq!(optimized.to_string(), "65316 -> h\n");
+        let circuit = Circuit::new(&state, 16).unwrap();
+        assert_eq!(circuit.signal("h").unwrap(), 65316);
+
+        let optimized = optimize(&state, &["d", "g"], 8).unwrap();
+        assert_eq!(optimized.to_string(), "72 -> d\n4 -> g\n");
+    }
+
+    #[test]
+    fn test_collapse_and_remove_dead() {
+        let input = "b -> a\nc -> b\nc AND 3 -> d\nNOT x -> c\n1 -> unused\nd OR b -> e";
+        let state = state_from_str(input).unwrap();
+        let optimized = optimize(&state, &["a", "e"], 16).unwrap();
+        assert_eq!(
+            optimized.to_string(),
+            "c -> a\nNOT x -> c\nc AND 3 -> d\nd OR c -> e\n"
+        );
+
+        // The simplified netlist reads back as an equivalent circuit.
+        let simplified = format!("{optimized}5 -> x");
+        let circuit = Circuit::new(&state_from_str(&simplified).unwrap(), 16).unwrap();
+        let original = format!("{input}\n5 -> x");
+        let expected = Circuit::new(&state_from_str(&original).unwrap(), 16).unwrap();
+        for wire in ["a", "e"] {
+            assert_eq!(
+                circuit.signal(wire).unwrap(),
+                expected.signal(wire).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_loop() {
+        let state = state_from_str("b -> a\na -> b\nb AND 1 -> c\nNOT 0 -> d").unwrap();
+        let optimized = optimize(&state, &["c", "d"], 16).unwrap();
+        assert_eq!(
+            optimized.to_string(),
+            "b -> a\na -> b\nb AND 1 -> c\n65535 -> d\n"
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use anyhow::{Error, Result, anyhow};
+use anyhow::{Result, anyhow};
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
 mod parser {
     use winnow::{
         Parser, Result,
@@ -35,7 +37,7 @@ mod parser {
     pub fn string(input: &mut &str) -> Result<String> {
         delimited(
             '"',
-            repeat(1.., alt((escaped_char, none_of(['"'])))).fold(String::new, |mut acc, ch| {
+            repeat(0.., alt((escaped_char, none_of(['"'])))).fold(String::new, |mut acc, ch| {
                 acc.push(ch);
                 acc
             }),
@@ -62,7 +64,7 @@ mod parser {
         delimited(
             '"',
             repeat(
-                1..,
+                0..,
                 alt((
                     doublescaped_char,
                     none_of(['"']).map(|ch| format!("{}", ch)),
@@ -110,27 +112,36 @@ fn doublescape(s: &str) -> Result<String> {
         .map_err(|err| anyhow!("\n{err}"))
 }
 
-async fn answer(mut parser: impl FnMut(&str) -> Result<String>) -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d08.txt").await?;
-    let escaped = input.lines().map(|line| parser(line.trim())).try_fold(
-        String::new(),
-        |mut acc, line| {
-            acc.push_str(&line?);
-            acc.push('\n');
-            Ok::<_, Error>(acc)
-        },
-    )?;
-    println!(
-        "Answer: {}",
-        input.len().max(escaped.len()) - escaped.len().min(input.len())
-    );
-    Ok(())
+/// Sums the differences between the lengths of every line of the `input`
+/// and its form produced by the `parser`.
+fn answer(input: &str, mut parser: impl FnMut(&str) -> Result<String>) -> Result<Answer> {
+    let mut difference = 0;
+    for line in input.lines() {
+        let line = line.trim();
+        difference += line.len().abs_diff(parser(line)?.len());
+    }
+    Ok(difference.into())
 }
 
-pub async fn p1() -> Result<()> {
-    answer(escape).await
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = &'a str;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
 
-pub async fn p2() -> Result<()> {
-    answer(doublescape).await
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str> {
+        Ok(input)
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer> {
+        answer(input, escape)
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer> {
+        answer(input, doublescape)
+    }
 }
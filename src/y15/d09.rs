@@ -6,10 +6,19 @@ use std::{
 use anyhow::{Context, Error, Result, anyhow};
 use winnow::Parser;
 
+use crate::{
+    answer::Answer,
+    common::dot::Graph,
+    input::InputArgs,
+    output::Format,
+    run::print_part,
+    solution::{Part, Solution},
+};
+
 mod parser {
     use winnow::{Parser, Result, ascii::dec_uint, combinator::separated_pair, token::take_while};
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     use super::Connection;
 
@@ -48,7 +57,7 @@ impl<'a> TryFrom<&'a str> for Connection<'a> {
 }
 
 #[derive(Debug)]
-struct World<'a> {
+pub struct World<'a> {
     connections: Vec<Connection<'a>>,
 }
 
@@ -125,6 +134,14 @@ impl<'a, 'w> Route<'a, 'w> {
         self.stops.push((distance, to));
     }
 
+    /// Pairs of cities the route goes between, in order.
+    fn legs(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let cities = self.stops.iter().map(|(_, stop)| *stop);
+        std::iter::once(self.initial)
+            .chain(cities.clone())
+            .zip(cities)
+    }
+
     fn unvisited_cities(&self) -> HashSet<&'a str> {
         let mut visited: HashSet<_> = self.stops.iter().map(|(_, stop)| *stop).collect();
         visited.insert(self.initial);
@@ -133,21 +150,12 @@ impl<'a, 'w> Route<'a, 'w> {
     }
 }
 
-async fn answer(f: impl FnOnce(Vec<Route>) -> Option<u32>) -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d09.txt").await?;
-
-    let input = input.lines();
-    let world: World = input
-        .enumerate()
-        .map(|(index, line)| {
-            Connection::try_from(line).with_context(|| format!("Failed to parse line {index}"))
-        })
-        .try_collect()?;
-
+/// Finds all routes visiting every city exactly once.
+fn routes<'a, 'w>(world: &'w World<'a>) -> Vec<Route<'a, 'w>> {
     let mut queue: Vec<_> = world
         .cities()
         .into_iter()
-        .map(|city| Route::new(&world, city))
+        .map(|city| Route::new(world, city))
         .collect();
 
     let mut done = vec![];
@@ -165,16 +173,138 @@ async fn answer(f: impl FnOnce(Vec<Route>) -> Option<u32>) -> Result<()> {
         }
     }
 
-    let answer = f(done).context("Valid route not found")?;
-    println!("Answer: {}", answer);
+    done
+}
 
-    Ok(())
+/// Finds the route answering the `part`, i.e. the shortest route for the
+/// first part and the longest one for the second.
+fn optimal_route<'a, 'w>(world: &'w World<'a>, part: Part) -> Result<Route<'a, 'w>> {
+    let routes = routes(world).into_iter();
+    let route = match part {
+        Part::One => routes.min_by_key(Route::distance),
+        Part::Two => routes.max_by_key(Route::distance),
+    };
+    route.context("Valid route not found")
 }
 
-pub async fn p1() -> Result<()> {
-    answer(|routes| routes.into_iter().map(|route| route.distance()).min()).await
+/// Draws the cities connected by edges labelled with their distances,
+/// highlighting the legs of the `route`.
+fn graph(world: &World, route: &Route) -> Graph {
+    let legs: HashSet<_> = route
+        .legs()
+        .flat_map(|(from, to)| [(from, to), (to, from)])
+        .collect();
+
+    let mut graph = Graph::undirected();
+    for connection in &world.connections {
+        let distance = connection.distance.to_string();
+        let mut attributes = vec![("label", distance.as_str())];
+        if legs.contains(&(connection.from, connection.to)) {
+            attributes.extend([("color", "red"), ("penwidth", "3")]);
+        }
+        graph.edge(connection.from, connection.to, &attributes);
+    }
+    graph
 }
 
-pub async fn p2() -> Result<()> {
-    answer(|routes| routes.into_iter().map(|route| route.distance()).max()).await
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = World<'a>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<World<'a>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Connection::try_from(line).with_context(|| format!("Failed to parse line {index}"))
+            })
+            .try_collect()
+    }
+
+    fn part1(&self, world: &World) -> Result<Answer> {
+        Ok(optimal_route(world, Part::One)?.distance().into())
+    }
+
+    fn part2(&self, world: &World) -> Result<Answer> {
+        Ok(optimal_route(world, Part::Two)?.distance().into())
+    }
+}
+
+/// Solves a part of the puzzle, e.g. `aoc y15 d09 p1`, or draws the cities.
+#[derive(clap::Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(required = true)]
+    part: Option<Part>,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    Graph(GraphArgs),
+}
+
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> Result<()> {
+        match (&self.command, self.part) {
+            (Some(Command::Graph(args)), _) => args.run(input).await,
+            (None, Some(part)) => print_part(&Solver, part, input, format).await,
+            (None, None) => unreachable!("Part is required without a command"),
+        }
+    }
+}
+
+/// Prints the cities and distances between them as a Graphviz graph, e.g.
+/// `aoc y15 d09 graph | neato -Tsvg > cities.svg`, highlighting the optimal
+/// route.
+#[derive(clap::Args)]
+struct GraphArgs {
+    /// Part of the puzzle whose route is highlighted, the shortest one for
+    /// `p1` and the longest one for `p2`.
+    #[arg(long, default_value = "p1")]
+    part: Part,
+}
+
+impl GraphArgs {
+    async fn run(&self, input: &InputArgs) -> Result<()> {
+        let input = input.read(&Solver).await?;
+        let world = Solver.parse(&input)?;
+        let route = optimal_route(&world, self.part)?;
+        print!("{}", graph(&world, &route));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Solver, graph, optimal_route};
+    use crate::solution::{Part, Solution};
+
+    const EXAMPLE: &str =
+        "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141";
+
+    #[test]
+    fn test_graph() {
+        let world = Solver.parse(EXAMPLE).unwrap();
+        let route = optimal_route(&world, Part::One).unwrap();
+        assert_eq!(route.distance(), 605);
+        assert_eq!(
+            graph(&world, &route).to_string(),
+            concat!(
+                "graph {\n",
+                "    \"London\" -- \"Dublin\" [label=\"464\", color=\"red\", penwidth=\"3\"];\n",
+                "    \"London\" -- \"Belfast\" [label=\"518\"];\n",
+                "    \"Dublin\" -- \"Belfast\" [label=\"141\", color=\"red\", penwidth=\"3\"];\n",
+                "}\n",
+            )
+        );
+    }
 }
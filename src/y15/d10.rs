@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use anyhow::Result;
 
-const INPUT: &str = "1113122113";
+use crate::{answer::Answer, solution::Solution};
 
 #[derive(Default, Debug)]
 struct Acc {
@@ -44,14 +44,41 @@ fn look_and_say(n: usize, input: &str) -> String {
     result
 }
 
-pub async fn p1() -> Result<()> {
-    let answer = look_and_say(40, INPUT);
-    println!("Answer: {}", answer.len());
-    Ok(())
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = &'a str;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str> {
+        Ok(input.trim())
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer> {
+        let answer = look_and_say(40, input);
+        Ok(answer.len().into())
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer> {
+        let answer = look_and_say(50, input);
+        Ok(answer.len().into())
+    }
 }
 
-pub async fn p2() -> Result<()> {
-    let answer = look_and_say(50, INPUT);
-    println!("Answer: {}", answer.len());
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::look_and_say;
+
+    #[test]
+    fn test_look_and_say() {
+        assert_eq!(look_and_say(1, "1"), "11");
+        assert_eq!(look_and_say(1, "11"), "21");
+        assert_eq!(look_and_say(1, "21"), "1211");
+        assert_eq!(look_and_say(1, "1211"), "111221");
+        assert_eq!(look_and_say(5, "1"), "312211");
+    }
 }
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
 
-const INPUT: &str = "vzbxkghb";
+use crate::{answer::Answer, solution::Solution};
 
 const INVALID_CHARS: &str = "iol";
 
@@ -88,25 +88,34 @@ fn find_next_password(curr: &str) -> Result<String> {
         .context("No valid password found")
 }
 
-fn find_first_password() -> Result<String> {
-    find_next_password(INPUT)
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
 
-pub async fn p1() -> Result<()> {
-    let password = find_first_password()?;
-    println!("Answer: {}", password);
-    Ok(())
-}
+impl Solution for Solver {
+    type Input<'a> = &'a str;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Corporate Policy";
 
-pub async fn p2() -> Result<()> {
-    let password = find_first_password().and_then(|pass| find_next_password(&pass))?;
-    println!("Answer: {}", password);
-    Ok(())
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str> {
+        Ok(input.trim())
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer> {
+        let password = find_next_password(input)?;
+        Ok(password.into())
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer> {
+        let password = find_next_password(input).and_then(|pass| find_next_password(&pass))?;
+        Ok(password.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::y15::d11::{PasswordIterator, is_password_valid};
+    use crate::y15::d11::{PasswordIterator, find_next_password, is_password_valid};
 
     #[test]
     fn test_password_iterator() {
@@ -122,4 +131,10 @@ mod tests {
         assert!(is_password_valid("ghjaabcc"));
         assert!(!is_password_valid("hijklmmn"));
     }
+
+    #[test]
+    fn test_find_next_password() {
+        assert_eq!(find_next_password("abcdefgh").unwrap(), "abcdffaa");
+        assert_eq!(find_next_password("ghijklmn").unwrap(), "ghjaabcc");
+    }
 }
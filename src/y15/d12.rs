@@ -1,6 +1,8 @@
 use anyhow::Result;
 use serde_json::Value;
 
+use crate::{answer::Answer, solution::Solution};
+
 fn sum(value: &Value) -> i64 {
     match value {
         Value::Number(number) => number.as_i64().unwrap(),
@@ -10,14 +12,6 @@ fn sum(value: &Value) -> i64 {
     }
 }
 
-pub async fn p1() -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d12.txt").await?;
-    let value: Value = serde_json::from_str(&input)?;
-    let sum = sum(&value);
-    println!("Answer: {}", sum);
-    Ok(())
-}
-
 fn sum_not_reds(value: &Value) -> i64 {
     match value {
         Value::Number(number) => number.as_i64().unwrap(),
@@ -33,10 +27,27 @@ fn sum_not_reds(value: &Value) -> i64 {
     }
 }
 
-pub async fn p2() -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d12.txt").await?;
-    let value: Value = serde_json::from_str(&input)?;
-    let sum = sum_not_reds(&value);
-    println!("Answer: {}", sum);
-    Ok(())
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Value;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+
+    fn parse(&self, input: &str) -> Result<Value> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    fn part1(&self, value: &Value) -> Result<Answer> {
+        let sum = sum(value);
+        Ok(sum.into())
+    }
+
+    fn part2(&self, value: &Value) -> Result<Answer> {
+        let sum = sum_not_reds(value);
+        Ok(sum.into())
+    }
 }
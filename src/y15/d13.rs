@@ -5,6 +5,8 @@ use itertools::Itertools;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
 mod parser {
     use winnow::{
         Parser, Result,
@@ -13,7 +15,7 @@ mod parser {
         token::{any, take_while},
     };
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     use super::{Relation, Relations};
 
@@ -57,11 +59,11 @@ mod parser {
     }
 }
 
-#[derive(Debug)]
-struct Relation<'a>(&'a str, i32, &'a str);
+#[derive(Debug, Clone)]
+pub struct Relation<'a>(&'a str, i32, &'a str);
 
-#[derive(Debug)]
-struct Relations<'a>(Vec<Relation<'a>>);
+#[derive(Debug, Clone)]
+pub struct Relations<'a>(Vec<Relation<'a>>);
 
 impl<'a> Relations<'a> {
     fn score(&self, a: &str, b: &str) -> Option<i32> {
@@ -133,7 +135,7 @@ impl Arrangement<'_, '_> {
     }
 }
 
-fn answer(relations: Relations) -> Result<()> {
+fn answer(relations: &Relations) -> Result<Answer> {
     let people = relations.people();
     let people_len = people.len();
 
@@ -141,32 +143,39 @@ fn answer(relations: Relations) -> Result<()> {
         .into_iter()
         .permutations(people_len)
         .par_bridge()
-        .map(|order| Arrangement {
-            relations: &relations,
-            order,
-        })
+        .map(|order| Arrangement { relations, order })
         .filter_map(|arrangement| arrangement.score())
         .max()
         .context("No arrangement found")?;
 
-    println!("Answer: {}", max_score);
-
-    Ok(())
+    Ok(max_score.into())
 }
 
-pub async fn p1() -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d13.txt").await?;
-    let relations = Relations::try_from(input.as_str())?;
-    answer(relations)
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
 
-pub async fn p2() -> Result<()> {
-    let input = tokio::fs::read_to_string("inputs/y15_d13.txt").await?;
-    let mut relations = Relations::try_from(input.as_str())?;
-    let my_relations = relations
-        .people()
-        .into_iter()
-        .flat_map(|person| [Relation("Maciek", 0, person), Relation(person, 0, "Maciek")]);
-    relations.extend(my_relations);
-    answer(relations)
+impl Solution for Solver {
+    type Input<'a> = Relations<'a>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Relations<'a>> {
+        Relations::try_from(input)
+    }
+
+    fn part1(&self, relations: &Relations) -> Result<Answer> {
+        answer(relations)
+    }
+
+    fn part2(&self, relations: &Relations) -> Result<Answer> {
+        let mut relations = relations.clone();
+        let my_relations = relations
+            .people()
+            .into_iter()
+            .flat_map(|person| [Relation("Maciek", 0, person), Relation(person, 0, "Maciek")]);
+        relations.extend(my_relations);
+        answer(&relations)
+    }
 }
@@ -4,6 +4,9 @@ use anyhow::{Context, Result, anyhow};
 use itertools::Itertools;
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
+/// Duration of the race in the puzzle.
 const RUN_DURATION: Seconds = 2503;
 
 mod parser {
@@ -13,7 +16,7 @@ mod parser {
         combinator::{delimited, separated},
     };
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     use super::Reindeer;
 
@@ -56,7 +59,7 @@ type KmPerSecond = u64;
 type Seconds = u64;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
-struct Reindeer {
+pub struct Reindeer {
     speed: KmPerSecond,
     r#move: Seconds,
     rest: Seconds,
@@ -71,47 +74,62 @@ impl Reindeer {
     }
 }
 
-async fn read_reindeers() -> Result<Vec<Reindeer>> {
-    let input = tokio::fs::read_to_string("inputs/y15_d14.txt").await?;
-    parser::reindeers
-        .parse(input.as_str())
-        .map_err(|err| anyhow!("{err}"))
-        .context("Failed to parse reindeers")
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Solver {
+    /// Duration of the race, in seconds.
+    duration: Seconds,
 }
 
-pub async fn p1() -> Result<()> {
-    let max_distance = read_reindeers()
-        .await?
-        .into_iter()
-        .map(|r| r.distance(RUN_DURATION))
-        .max()
-        .context("No reindeers?")?;
-    println!("Answer: {}", max_distance);
-    Ok(())
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            duration: RUN_DURATION,
+        }
+    }
 }
 
-pub async fn p2() -> Result<()> {
-    let reindeers: HashMap<_, u64> = read_reindeers()
-        .await?
-        .into_iter()
-        .map(|reindeer| (reindeer, 0))
-        .collect();
-    let max_score = (1..RUN_DURATION)
-        .fold(reindeers, |mut scoreboard, passed| {
-            let leaders = scoreboard
-                .keys()
-                .copied()
-                .max_set_by(|a, b| a.distance(passed).cmp(&b.distance(passed)));
-            for leader in leaders {
-                if let Some(score) = scoreboard.get_mut(&leader) {
-                    *score += 1;
+impl Solution for Solver {
+    type Input<'a> = Vec<Reindeer>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+
+    fn parse(&self, input: &str) -> Result<Vec<Reindeer>> {
+        parser::reindeers
+            .parse(input)
+            .map_err(|err| anyhow!("{err}"))
+            .context("Failed to parse reindeers")
+    }
+
+    fn part1(&self, reindeers: &Vec<Reindeer>) -> Result<Answer> {
+        let max_distance = reindeers
+            .iter()
+            .map(|r| r.distance(self.duration))
+            .max()
+            .context("No reindeers?")?;
+        Ok(max_distance.into())
+    }
+
+    fn part2(&self, reindeers: &Vec<Reindeer>) -> Result<Answer> {
+        let reindeers: HashMap<_, u64> = reindeers.iter().map(|&reindeer| (reindeer, 0)).collect();
+        let max_score = (1..self.duration)
+            .fold(reindeers, |mut scoreboard, passed| {
+                let leaders = scoreboard
+                    .keys()
+                    .copied()
+                    .max_set_by(|a, b| a.distance(passed).cmp(&b.distance(passed)));
+                for leader in leaders {
+                    if let Some(score) = scoreboard.get_mut(&leader) {
+                        *score += 1;
+                    }
                 }
-            }
-            scoreboard
-        })
-        .into_values()
-        .max()
-        .context("No reindeers?")?;
-    println!("Answer: {}", max_score);
-    Ok(())
+                scoreboard
+            })
+            .into_values()
+            .max()
+            .context("No reindeers?")?;
+        Ok(max_score.into())
+    }
 }
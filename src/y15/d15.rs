@@ -3,6 +3,8 @@ use std::{collections::HashMap, ops::Mul};
 use anyhow::{Context, Result, anyhow};
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
 mod parser {
     use winnow::{
         Parser, Result,
@@ -13,7 +15,7 @@ mod parser {
         token::{any, take_while},
     };
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     use super::Ingredient;
 
@@ -62,7 +64,7 @@ mod parser {
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
-struct Ingredient {
+pub struct Ingredient {
     capacity: i32,
     durability: i32,
     flavor: i32,
@@ -84,14 +86,6 @@ impl Mul<u32> for &Ingredient {
     }
 }
 
-async fn read_ingredients() -> Result<Vec<Ingredient>> {
-    let input = tokio::fs::read_to_string("inputs/y15_d15.txt").await?;
-    let ingredients = parser::ingredients
-        .parse(input.as_str())
-        .map_err(|err| anyhow!("{err}"))?;
-    Ok(ingredients)
-}
-
 #[derive(Debug)]
 struct Cookie<'a> {
     ingredients: HashMap<&'a Ingredient, u32>,
@@ -188,25 +182,38 @@ impl<'a> From<Vec<&'a Ingredient>> for Cookie<'a> {
     }
 }
 
-pub async fn p1() -> Result<()> {
-    let ingredients = read_ingredients().await?;
-    let best_score = Cookie::all_from(&ingredients)
-        .into_iter()
-        .map(|cookie| cookie.score())
-        .max()
-        .context("No cookie found?")?;
-    println!("{:?}", best_score);
-    Ok(())
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Ingredient>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
 
-pub async fn p2() -> Result<()> {
-    let ingredients = read_ingredients().await?;
-    let best_score = Cookie::all_from(&ingredients)
-        .into_iter()
-        .filter(|cookie| cookie.calories() == 500)
-        .map(|cookie| cookie.score())
-        .max()
-        .context("No cookie found?")?;
-    println!("{:?}", best_score);
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Vec<Ingredient>> {
+        parser::ingredients
+            .parse(input)
+            .map_err(|err| anyhow!("{err}"))
+    }
+
+    fn part1(&self, ingredients: &Vec<Ingredient>) -> Result<Answer> {
+        let best_score = Cookie::all_from(ingredients)
+            .into_iter()
+            .map(|cookie| cookie.score())
+            .max()
+            .context("No cookie found?")?;
+        Ok(best_score.into())
+    }
+
+    fn part2(&self, ingredients: &Vec<Ingredient>) -> Result<Answer> {
+        let best_score = Cookie::all_from(ingredients)
+            .into_iter()
+            .filter(|cookie| cookie.calories() == 500)
+            .map(|cookie| cookie.score())
+            .max()
+            .context("No cookie found?")?;
+        Ok(best_score.into())
+    }
 }
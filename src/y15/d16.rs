@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use anyhow::{Context, Result, anyhow};
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
 mod parser {
     use winnow::{
         Parser, Result,
@@ -11,7 +13,7 @@ mod parser {
     };
 
     use super::Aunt;
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     fn property_name<'a>(input: &mut &'a str) -> Result<&'a str> {
         alpha1(input)
@@ -39,7 +41,7 @@ mod parser {
 }
 
 #[derive(Debug)]
-struct Aunt<'a> {
+pub struct Aunt<'a> {
     num: u32,
     properties: HashMap<&'a str, u32>,
 }
@@ -60,23 +62,19 @@ impl Condition {
     }
 }
 
-/// Helper function which reads and parses the input and returns the number of the best aunt
-async fn find_best_aunt<F>(score_fn: F) -> Result<u32>
+/// Helper function which returns the number of the best aunt
+fn find_best_aunt<F>(aunts: &[Aunt], score_fn: F) -> Result<u32>
 where
     F: Fn(&Aunt) -> usize,
 {
-    let input = tokio::fs::read_to_string("inputs/y15_d16.txt").await?;
-    let aunts = parser::aunts
-        .parse(input.as_str())
-        .map_err(|err| anyhow!("{err}"))?;
     let best_aunt = aunts
-        .into_iter()
+        .iter()
         .max_by(|a, b| score_fn(a).cmp(&score_fn(b)))
         .context("Aunts doesn't exist!")?;
     Ok(best_aunt.num)
 }
 
-pub async fn p1() -> Result<()> {
+fn p1(aunts: &[Aunt]) -> Result<Answer> {
     let required_props: HashMap<&str, u32> = vec![
         ("children", 3),
         ("cats", 7),
@@ -99,11 +97,10 @@ pub async fn p1() -> Result<()> {
             .count()
     };
 
-    println!("Answer: {}", find_best_aunt(score_aunt).await?);
-    Ok(())
+    Ok(find_best_aunt(aunts, score_aunt)?.into())
 }
 
-pub async fn p2() -> Result<()> {
+fn p2(aunts: &[Aunt]) -> Result<Answer> {
     let required_props: HashMap<&str, Condition> = vec![
         ("children", Condition::Equal(3)),
         ("cats", Condition::Greater(7)),
@@ -130,6 +127,28 @@ pub async fn p2() -> Result<()> {
             .count()
     };
 
-    println!("Answer: {}", find_best_aunt(score_aunt).await?);
-    Ok(())
+    Ok(find_best_aunt(aunts, score_aunt)?.into())
+}
+
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Aunt<'a>>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Vec<Aunt<'a>>> {
+        parser::aunts.parse(input).map_err(|err| anyhow!("{err}"))
+    }
+
+    fn part1(&self, aunts: &Vec<Aunt>) -> Result<Answer> {
+        p1(aunts)
+    }
+
+    fn part2(&self, aunts: &Vec<Aunt>) -> Result<Answer> {
+        p2(aunts)
+    }
 }
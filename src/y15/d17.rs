@@ -1,51 +1,72 @@
 use anyhow::Result;
 use itertools::Itertools;
 
+use crate::{answer::Answer, solution::Solution};
+
 type Liters = u32;
 
+/// Volume of eggnog to store in the puzzle.
 const EGGNOG_VOLUME: Liters = 150;
 
-async fn read_containers() -> Result<Vec<Liters>> {
-    let input = tokio::fs::read_to_string("inputs/y15_d17.txt").await?;
-    input
-        .lines()
-        .map(|line| line.parse::<Liters>().map_err(anyhow::Error::from))
-        .try_collect()
-}
-
-fn get_valid_combos(containers: &[Liters]) -> Vec<Vec<&Liters>> {
+fn get_valid_combos(containers: &[Liters], volume: Liters) -> Vec<Vec<&Liters>> {
     (1..=containers.len())
         .flat_map(|len| {
             containers
                 .iter()
                 .combinations(len)
-                .filter(move |combo| combo.iter().copied().sum::<Liters>() == EGGNOG_VOLUME)
+                .filter(move |combo| combo.iter().copied().sum::<Liters>() == volume)
         })
         .collect()
 }
 
-pub async fn p1() -> Result<()> {
-    let containers = read_containers().await?;
-    let valid_combos = get_valid_combos(&containers);
-    println!("Answer: {}", valid_combos.len());
-    Ok(())
+#[derive(serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Solver {
+    /// Volume of eggnog the containers must hold.
+    volume: Liters,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            volume: EGGNOG_VOLUME,
+        }
+    }
 }
 
-pub async fn p2() -> Result<()> {
-    let containers = read_containers().await?;
-    let valid_combos = get_valid_combos(&containers);
+impl Solution for Solver {
+    type Input<'a> = Vec<Liters>;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "No Such Thing as Too Much";
+
+    fn parse(&self, input: &str) -> Result<Vec<Liters>> {
+        input
+            .lines()
+            .map(|line| line.parse::<Liters>().map_err(anyhow::Error::from))
+            .try_collect()
+    }
+
+    fn part1(&self, containers: &Vec<Liters>) -> Result<Answer> {
+        let valid_combos = get_valid_combos(containers, self.volume);
+        Ok(valid_combos.len().into())
+    }
+
+    fn part2(&self, containers: &Vec<Liters>) -> Result<Answer> {
+        let valid_combos = get_valid_combos(containers, self.volume);
 
-    let min_container_count = valid_combos
-        .iter()
-        .map(|combo| combo.len())
-        .min()
-        .unwrap_or(0);
+        let min_container_count = valid_combos
+            .iter()
+            .map(|combo| combo.len())
+            .min()
+            .unwrap_or(0);
 
-    let count = valid_combos
-        .into_iter()
-        .filter(|combo| combo.len() == min_container_count)
-        .count();
+        let count = valid_combos
+            .into_iter()
+            .filter(|combo| combo.len() == min_container_count)
+            .count();
 
-    println!("Answer: {}", count);
-    Ok(())
+        Ok(count.into())
+    }
 }
@@ -1,53 +1,29 @@
 use std::str::FromStr;
 
-use anyhow::{Context, Error, Result, anyhow};
+use anyhow::{Error, Result, anyhow};
 use itertools::Itertools;
 use winnow::Parser;
 
+use crate::{answer::Answer, solution::Solution};
+
 type Molecule = Vec<Atom>;
 
 type Atom = String;
 
 #[derive(Debug)]
-struct Input {
+pub struct Medicine {
     replacements: Vec<(Atom, Molecule)>,
     molecule: Molecule,
 }
 
-impl Input {
-    pub async fn read() -> Result<Self> {
-        let input = tokio::fs::read_to_string("inputs/y15_d19.txt")
-            .await
-            .context("Failed to read input file")?;
-
-        let input = input
-            .parse()
-            .map_err(|err| anyhow!("Failed to parse input: {}", err))?;
-
-        Ok(input)
-    }
-}
-
-impl FromStr for Input {
+impl FromStr for Medicine {
     type Err = Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        parser::input.parse(s).map_err(|err| anyhow!("{err}"))
+        parser::medicine.parse(s).map_err(|err| anyhow!("{err}"))
     }
 }
 
-pub async fn p1() -> Result<()> {
-    let input = Input::read().await?;
-
-    let replacements = expand_replacements(&input.molecule, &input.replacements)
-        .unique()
-        .count();
-
-    println!("Answer: {}", replacements);
-
-    Ok(())
-}
-
 fn expand_replacements(
     molecule: &[Atom],
     replacements: &[(Atom, Vec<Atom>)],
@@ -71,11 +47,34 @@ fn expand_replacement(
         })
 }
 
-pub async fn p2() -> Result<()> {
-    let input = Input::read().await?;
-    let steps = count_steps_to_e(&input.molecule);
-    println!("Answer: {}", steps);
-    Ok(())
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Medicine;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+
+    fn parse(&self, input: &str) -> Result<Medicine> {
+        input
+            .parse()
+            .map_err(|err| anyhow!("Failed to parse input: {}", err))
+    }
+
+    fn part1(&self, medicine: &Medicine) -> Result<Answer> {
+        let replacements = expand_replacements(&medicine.molecule, &medicine.replacements)
+            .unique()
+            .count();
+
+        Ok(replacements.into())
+    }
+
+    fn part2(&self, medicine: &Medicine) -> Result<Answer> {
+        let steps = count_steps_to_e(&medicine.molecule);
+        Ok(steps.into())
+    }
 }
 
 /// Counts the steps required using the known puzzle property:
@@ -95,9 +94,9 @@ mod parser {
         token::any,
     };
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
-    use super::{Atom, Input};
+    use super::{Atom, Medicine};
 
     fn atom(input: &mut &str) -> Result<Atom> {
         (
@@ -117,13 +116,13 @@ mod parser {
         repeat(1.., atom).parse_next(input)
     }
 
-    pub fn input(input: &mut &str) -> Result<Input> {
+    pub fn medicine(input: &mut &str) -> Result<Medicine> {
         separated_pair(
             separated(1.., replacement, newline),
             (newline, newline),
             molecule,
         )
-        .map(|(replacements, molecule)| Input {
+        .map(|(replacements, molecule)| Medicine {
             replacements,
             molecule,
         })
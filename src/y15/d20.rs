@@ -1,30 +1,12 @@
 use anyhow::{Context, Result};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-const INPUT: u32 = 29_000_000;
-
-pub async fn p1() -> Result<()> {
-    let answer = (1..INPUT)
-        .into_par_iter()
-        .find_first(|&house_index| inf_presents_count(house_index) >= INPUT)
-        .context("Failed to find the house number")?;
-    println!("Answer: {}", answer);
-    Ok(())
-}
+use crate::{answer::Answer, solution::Solution};
 
 fn inf_presents_count(house_index: u32) -> u32 {
     divisors(house_index).map(|d| d * 10).sum()
 }
 
-pub async fn p2() -> Result<()> {
-    let answer = (1..INPUT)
-        .into_par_iter()
-        .find_first(|&house_index| fin_presents_count(house_index) >= INPUT)
-        .context("Failed to find the house number")?;
-    println!("Answer: {}", answer);
-    Ok(())
-}
-
 fn fin_presents_count(house_index: u32) -> u32 {
     divisors(house_index)
         .filter(|&elf| house_index <= elf * 50)
@@ -39,6 +21,55 @@ fn divisors(n: u32) -> impl Iterator<Item = u32> {
     let sqrt_n = (n as f64).sqrt() as u32;
 
     (1..=sqrt_n)
-        .filter(move |&i| n % i == 0)
+        .filter(move |&i| n.is_multiple_of(i))
         .flat_map(move |i| if i * i == n { vec![i] } else { vec![i, n / i] })
 }
+
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = u32;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+
+    fn parse(&self, input: &str) -> Result<u32> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part1(&self, &presents: &u32) -> Result<Answer> {
+        let answer = (1..presents)
+            .into_par_iter()
+            .find_first(|&house_index| inf_presents_count(house_index) >= presents)
+            .context("Failed to find the house number")?;
+        Ok(answer.into())
+    }
+
+    fn part2(&self, &presents: &u32) -> Result<Answer> {
+        let answer = (1..presents)
+            .into_par_iter()
+            .find_first(|&house_index| fin_presents_count(house_index) >= presents)
+            .context("Failed to find the house number")?;
+        Ok(answer.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Solver, inf_presents_count};
+    use crate::{answer::Answer, solution::Solution};
+
+    #[test]
+    fn test_inf_presents_count() {
+        let presents: Vec<_> = (1..=9).map(inf_presents_count).collect();
+        assert_eq!(presents, [10, 30, 40, 70, 60, 120, 80, 150, 130]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Solver.part1(&70).unwrap(), Answer::UInt(4));
+        assert_eq!(Solver.part1(&130).unwrap(), Answer::UInt(8));
+    }
+}
@@ -4,61 +4,64 @@ use anyhow::{Context, Error, Result, anyhow};
 use itertools::Itertools;
 use winnow::Parser;
 
-/// Solves the first puzzle from the 21st day of the Advent of Code 2015 event.
-pub async fn p1() -> Result<()> {
-    let shop = Shop::default();
-    let enemy = read_enemy().await?;
-    let mut equipments: Vec<_> = Equipment::all(&shop).collect();
-    equipments.sort_unstable_by_key(|eq| eq.cost());
-
-    let won_eq = equipments.into_iter().find(|eq| {
-        find_winner(
-            &Character {
-                health: 100,
-                armor: eq.armor(),
-                damage: eq.damage(),
-            },
-            &enemy,
-        ) == Winner::Player
-    });
-
-    println!(
-        "Answer: {}",
-        won_eq.context("Winning equipment not found")?.cost()
-    );
-
-    Ok(())
-}
+use crate::{answer::Answer, solution::Solution};
 
-/// Solves the second puzzle from the 21st day of the Advent of Code 2015 event.
-pub async fn p2() -> Result<()> {
-    let shop = Shop::default();
-    let enemy = read_enemy().await?;
-    let mut equipments: Vec<_> = Equipment::all(&shop).collect();
-    equipments.sort_unstable_by_key(|eq| std::cmp::Reverse(eq.cost()));
-    let lose_eq = equipments.iter().find(|eq| {
-        find_winner(
-            &Character {
-                health: 100,
-                armor: eq.armor(),
-                damage: eq.damage(),
-            },
-            &enemy,
-        ) == Winner::Enemy
-    });
-
-    println!(
-        "Answer: {}",
-        lose_eq.context("Winning equipment not found")?.cost()
-    );
-
-    Ok(())
-}
+#[derive(Default, serde::Deserialize)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Character;
+
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "RPG Simulator 20XX";
 
-/// Reads the input data for the puzzle.
-async fn read_enemy() -> Result<Character> {
-    let input = tokio::fs::read_to_string("inputs/y15_d21.txt").await?;
-    input.parse().map_err(|err| anyhow!("{}", err))
+    /// Parses the enemy's statistics from the input data for the puzzle.
+    fn parse(&self, input: &str) -> Result<Character> {
+        input.parse().map_err(|err| anyhow!("{}", err))
+    }
+
+    /// Solves the first puzzle from the 21st day of the Advent of Code 2015 event.
+    fn part1(&self, enemy: &Character) -> Result<Answer> {
+        let shop = Shop::default();
+        let mut equipments: Vec<_> = Equipment::all(&shop).collect();
+        equipments.sort_unstable_by_key(|eq| eq.cost());
+
+        let won_eq = equipments.into_iter().find(|eq| {
+            find_winner(
+                &Character {
+                    health: 100,
+                    armor: eq.armor(),
+                    damage: eq.damage(),
+                },
+                enemy,
+            ) == Winner::Player
+        });
+
+        Ok(won_eq.context("Winning equipment not found")?.cost().into())
+    }
+
+    /// Solves the second puzzle from the 21st day of the Advent of Code 2015 event.
+    fn part2(&self, enemy: &Character) -> Result<Answer> {
+        let shop = Shop::default();
+        let mut equipments: Vec<_> = Equipment::all(&shop).collect();
+        equipments.sort_unstable_by_key(|eq| std::cmp::Reverse(eq.cost()));
+        let lose_eq = equipments.iter().find(|eq| {
+            find_winner(
+                &Character {
+                    health: 100,
+                    armor: eq.armor(),
+                    damage: eq.damage(),
+                },
+                enemy,
+            ) == Winner::Enemy
+        });
+
+        Ok(lose_eq
+            .context("Winning equipment not found")?
+            .cost()
+            .into())
+    }
 }
 
 /// Compares statistics of the `player` and the `enemy` [`Character`]s and returns
@@ -232,7 +235,7 @@ impl FromStr for Shop {
 
 /// An enemy's properties.
 #[derive(Debug, Clone)]
-struct Character {
+pub struct Character {
     health: u8,
     damage: u8,
     armor: u8,
@@ -253,7 +256,7 @@ mod parser {
         combinator::{alt, opt, separated, seq},
     };
 
-    use crate::y15::ws;
+    use crate::common::parsing::ws;
 
     use super::{Character, Item, ItemKind};
 
@@ -1,230 +1,101 @@
-use std::{
-    iter::Step,
-    ops::{Add, Mul, Sub},
-};
-
-use winnow::{
-    Parser,
-    ascii::space0,
-    combinator::delimited,
-    error::ParserError,
-    stream::{AsChar, Stream, StreamIsPartial},
-};
-
-macro_rules! define_year {
-    ($($day_num:ident),+) => {
-        $( mod $day_num; )*
-
-        #[derive(clap::Subcommand)]
-        enum DaySubcommand {
-            P1,
-            P2,
-        }
-
-        #[derive(clap::Args)]
-        struct DayArgs {
-            #[command(subcommand)]
-            command: DaySubcommand,
-        }
-
-        #[derive(clap::Subcommand)]
-        #[allow(non_camel_case_types)]
-        enum Subcommand {
-            $(
-                $day_num(DayArgs),
-            )*
-        }
+use anyhow::Context;
 
-        #[derive(clap::Args)]
-        pub struct Args {
-            #[command(subcommand)]
-            command: Subcommand,
-        }
-
-        impl Args {
-            pub async fn run(&self) -> anyhow::Result<()> {
-                match &self.command {
-                    $(
-                        Subcommand::$day_num(args) => {
-                            match args.command {
-                                DaySubcommand::P1 => $day_num::p1().await,
-                                DaySubcommand::P2 => $day_num::p2().await,
-                            }
-                        }
-                    )*
-                }
-            }
-        }
-    };
-}
+use crate::{input::InputArgs, output::Format, run::run_part, solution::Part};
 
 define_year!(
     d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d17, d18, d19,
     d20, d21
 );
 
-#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Clone, Copy)]
-pub struct Vec2<T> {
-    x: T,
-    y: T,
-}
-
-impl<T> Vec2<T> {
-    pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
-    }
-}
-
-impl<T> Vec2<T>
-where
-    T: Mul<T> + Copy,
-{
-    pub fn area(&self) -> T::Output {
-        self.x * self.y
-    }
-}
-
-impl<T> Vec2<T>
-where
-    T: Add<T> + Copy,
-    <T as Add<T>>::Output: Mul<i32>,
-{
-    pub fn perimeter(&self) -> <<T as Add<T>>::Output as Mul<i32>>::Output {
-        (self.x + self.y) * 2
-    }
-}
-
-impl<T> Vec2<T>
-where
-    T: Step + Copy,
-{
-    pub fn points_to_inclusive(self, rhs: Vec2<T>) -> PointRangeInclusive<T> {
-        PointRangeInclusive {
-            start: self,
-            end: rhs,
-            curr: None,
-        }
-    }
-}
+/// Year of the event solved by this module.
+const YEAR: u16 = 2015;
 
-impl<Lhs, Rhs> Add<Vec2<Rhs>> for Vec2<Lhs>
-where
-    Lhs: Add<Rhs, Output = Lhs>,
-{
-    type Output = Self;
-
-    fn add(self, rhs: Vec2<Rhs>) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
+/// Solves a single part of a puzzle from 2015, e.g. `aoc y15 d07 p1`, or
+/// runs a tool specific to a day.
+#[derive(clap::Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Args {
+    #[command(subcommand)]
+    tool: Option<Tool>,
 
-impl<Lhs, Rhs> Sub<Vec2<Rhs>> for Vec2<Lhs>
-where
-    Lhs: Sub<Rhs, Output = Lhs>,
-{
-    type Output = Self;
+    /// Day of the puzzle, e.g. `d07`.
+    #[arg(value_parser = parse_day, required = true)]
+    day: Option<u8>,
 
-    fn sub(self, rhs: Vec2<Rhs>) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
+    #[arg(required = true)]
+    part: Option<Part>,
 }
 
-/// An inclusive range of points in a 2D grid.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct PointRangeInclusive<T>
-where
-    T: Step + Copy,
-{
-    start: Vec2<T>,
-    end: Vec2<T>,
-    curr: Option<Vec2<T>>,
+/// Days with options beyond solving their parts.
+#[derive(clap::Subcommand)]
+enum Tool {
+    /// Solves day 6 with a chosen implementation of the grid of lights.
+    D06(d06::Args),
+    /// Solves day 7 or explores its circuit.
+    D07(d07::Args),
+    /// Solves day 9 or draws its cities.
+    D09(d09::Args),
 }
 
-impl<T> Iterator for PointRangeInclusive<T>
-where
-    T: Step + Copy,
-{
-    type Item = Vec2<T>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(curr) = &mut self.curr {
-            if curr.x < self.end.x {
-                curr.x = T::forward_checked(curr.x, 1)?;
-                return Some(*curr);
-            }
-
-            curr.x = self.start.x;
-            if curr.y < self.end.y {
-                curr.y = T::forward_checked(curr.y, 1)?;
-                return Some(*curr);
-            }
-
-            None
-        } else {
-            self.curr = Some(self.start);
-            self.curr
+impl Args {
+    pub async fn run(&self, input: &InputArgs, format: Format) -> anyhow::Result<()> {
+        match (&self.tool, self.day, self.part) {
+            (Some(Tool::D06(args)), _, _) => args.run(input, format).await,
+            (Some(Tool::D07(args)), _, _) => args.run(input, format).await,
+            (Some(Tool::D09(args)), _, _) => args.run(input, format).await,
+            (None, Some(day), Some(part)) => run_part(YEAR, day, part, input, format).await,
+            (None, _, _) => unreachable!("Day and part are required without a tool"),
         }
     }
 }
 
-/// Creates a new [`Parser`] that accepts and trims any number of
-/// whitespace characters around the provided `parser` and returns it's
-/// result.
-fn ws<I, O, E>(parser: impl Parser<I, O, E>) -> impl Parser<I, O, E>
-where
-    I: Stream + StreamIsPartial,
-    I::Token: AsChar + Clone,
-    E: ParserError<I>,
-{
-    delimited(space0, parser, space0)
+/// Parses a day in the `dNN` format used by the CLI.
+fn parse_day(s: &str) -> anyhow::Result<u8> {
+    let day = s.strip_prefix('d').context("Day must start with \"d\"")?;
+    Ok(day.parse()?)
 }
 
 #[cfg(test)]
 mod tests {
-    mod point_range_inclusive {
-        use crate::y15::Vec2;
-
-        #[test]
-        fn test_single_point() {
-            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(0, 0));
-            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
-            assert_eq!(range.next(), None);
-        }
+    use clap::Parser;
 
-        #[test]
-        fn test_horizontal_line_points() {
-            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(3, 0));
-            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(1, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(2, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(3, 0)));
-            assert_eq!(range.next(), None);
-        }
+    use super::{Args, Tool};
+    use crate::solution::Part;
 
-        #[test]
-        fn test_vertical_line_points() {
-            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(0, 3));
-            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(0, 1)));
-            assert_eq!(range.next(), Some(Vec2::new(0, 2)));
-            assert_eq!(range.next(), Some(Vec2::new(0, 3)));
-            assert_eq!(range.next(), None);
-        }
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: Args,
+    }
 
-        #[test]
-        fn test_rect_points() {
-            let mut range = Vec2::new(0, 0).points_to_inclusive(Vec2::new(1, 1));
-            assert_eq!(range.next(), Some(Vec2::new(0, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(1, 0)));
-            assert_eq!(range.next(), Some(Vec2::new(0, 1)));
-            assert_eq!(range.next(), Some(Vec2::new(1, 1)));
-            assert_eq!(range.next(), None);
-        }
+    fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+        Cli::try_parse_from(["y15"].iter().chain(args)).map(|cli| cli.args)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["d05", "p1"]).unwrap();
+        assert!(args.tool.is_none());
+        assert_eq!((args.day, args.part), (Some(5), Some(Part::One)));
+
+        let args = parse(&["d06", "2", "--layout", "flat"]).unwrap();
+        assert!(matches!(args.tool, Some(Tool::D06(_))));
+
+        let args = parse(&["d07", "eval", "a", "--set", "b=3176", "--set", "c=0"]).unwrap();
+        assert!(matches!(args.tool, Some(Tool::D07(_))));
+        assert!(parse(&["d07", "p2"]).is_ok());
+        assert!(parse(&["d07", "graph", "--width", "8"]).is_ok());
+        assert!(parse(&["d07", "optimize", "a", "--set", "b=3176"]).is_ok());
+
+        let args = parse(&["d09", "graph", "--part", "p2"]).unwrap();
+        assert!(matches!(args.tool, Some(Tool::D09(_))));
+        assert!(parse(&["d09", "p1"]).is_ok());
+
+        assert!(parse(&["d05"]).is_err());
+        assert!(parse(&["d07"]).is_err());
+        assert!(parse(&["d07", "eval", "a", "--set", "b"]).is_err());
+        assert!(parse(&["d07", "optimize"]).is_err());
+        assert!(parse(&["d09", "graph", "p1"]).is_err());
+        assert!(parse(&["7", "p1"]).is_err());
     }
 }